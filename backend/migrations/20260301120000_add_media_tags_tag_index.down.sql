@@ -0,0 +1 @@
+DROP INDEX IF EXISTS idx_media_tags_tag_id;
@@ -0,0 +1 @@
+CREATE INDEX idx_media_tags_tag_id ON media_tags(tag_id);
@@ -0,0 +1,6 @@
+ALTER TABLE invites
+    DROP CONSTRAINT IF EXISTS invites_used_count_within_limit,
+    DROP CONSTRAINT IF EXISTS invites_max_uses_positive,
+    DROP COLUMN IF EXISTS role,
+    DROP COLUMN IF EXISTS used_count,
+    DROP COLUMN IF EXISTS max_uses;
@@ -0,0 +1,10 @@
+ALTER TABLE invites
+    ADD COLUMN max_uses INTEGER NOT NULL DEFAULT 1,
+    ADD COLUMN used_count INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN role user_role NOT NULL DEFAULT 'member';
+
+UPDATE invites SET used_count = 1 WHERE used_by IS NOT NULL;
+
+ALTER TABLE invites
+    ADD CONSTRAINT invites_max_uses_positive CHECK (max_uses > 0),
+    ADD CONSTRAINT invites_used_count_within_limit CHECK (used_count BETWEEN 0 AND max_uses);
@@ -0,0 +1 @@
+ALTER TABLE media ADD COLUMN ocr_confidence REAL;
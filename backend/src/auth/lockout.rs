@@ -0,0 +1,92 @@
+//! Per-username throttling of failed logins.
+//!
+//! After `MAX_FAILED_ATTEMPTS` consecutive failures the username is locked
+//! for `LOCKOUT_MINUTES`. Failures older than the lockout window no longer
+//! count, so occasional typos never add up to a lockout.
+//!
+//! Every login attempt is counted up front and the counter is cleared again
+//! on success, so concurrent attempts can't all slip past the limit while
+//! their passwords are being verified.
+
+use sqlx::PgPool;
+
+use crate::error::AppError;
+
+pub const MAX_FAILED_ATTEMPTS: i32 = 5;
+pub const LOCKOUT_MINUTES: i32 = 15;
+
+/// Counts a login attempt for the username, or returns `AccountLocked` if the
+/// username is currently locked out.
+///
+/// The lock check and the increment happen in a single statement. The attempt
+/// that reaches the limit sets the lock; a successful login then lifts it
+/// again through [`reset`].
+pub async fn begin_attempt(db: &PgPool, username: &str) -> Result<(), AppError> {
+    prune_expired(db).await?;
+
+    let counted: Option<String> = sqlx::query_scalar(
+        "INSERT INTO login_attempts (username, failed_count, last_failed_at)
+         VALUES ($1, 1, now())
+         ON CONFLICT (username) DO UPDATE SET
+             failed_count = CASE
+                 WHEN login_attempts.last_failed_at < now() - make_interval(mins => $3)
+                 THEN 1
+                 ELSE login_attempts.failed_count + 1
+             END,
+             last_failed_at = now(),
+             locked_until = CASE
+                 WHEN login_attempts.last_failed_at >= now() - make_interval(mins => $3)
+                     AND login_attempts.failed_count + 1 >= $2
+                 THEN now() + make_interval(mins => $3)
+             END
+         WHERE login_attempts.locked_until IS NULL OR login_attempts.locked_until <= now()
+         RETURNING username",
+    )
+    .bind(username)
+    .bind(MAX_FAILED_ATTEMPTS)
+    .bind(LOCKOUT_MINUTES)
+    .fetch_optional(db)
+    .await?;
+
+    if counted.is_some() {
+        return Ok(());
+    }
+
+    let remaining: Option<i64> = sqlx::query_scalar(
+        "SELECT CEIL(EXTRACT(EPOCH FROM locked_until - now()))::bigint
+         FROM login_attempts
+         WHERE username = $1",
+    )
+    .bind(username)
+    .fetch_optional(db)
+    .await?;
+
+    Err(AppError::AccountLocked {
+        retry_after_secs: remaining.unwrap_or(1).max(1),
+    })
+}
+
+/// Clears the failure counter after a successful login.
+pub async fn reset(db: &PgPool, username: &str) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM login_attempts WHERE username = $1")
+        .bind(username)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+/// Deletes counters that no longer affect any lockout.
+///
+/// Failed logins are counted for unknown usernames too, so without this the
+/// table would grow with every name anyone has ever tried.
+async fn prune_expired(db: &PgPool) -> Result<(), AppError> {
+    sqlx::query(
+        "DELETE FROM login_attempts
+         WHERE last_failed_at < now() - make_interval(mins => $1)
+           AND (locked_until IS NULL OR locked_until <= now())",
+    )
+    .bind(LOCKOUT_MINUTES)
+    .execute(db)
+    .await?;
+    Ok(())
+}
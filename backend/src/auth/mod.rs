@@ -1,3 +1,4 @@
 pub mod jwt;
+pub mod lockout;
 pub mod middleware;
 pub mod password;
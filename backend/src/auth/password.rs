@@ -21,3 +21,181 @@ pub fn verify_password(password: &str, hash: &str) -> Result<bool, AppError> {
         .verify_password(password.as_bytes(), &parsed_hash)
         .is_ok())
 }
+
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+pub const MAX_PASSWORD_LENGTH: usize = 128;
+/// Rough entropy estimate (length × log2 of the character pool) a password must reach.
+const MIN_ENTROPY_BITS: f64 = 40.0;
+const MIN_DISTINCT_CHARS: usize = 5;
+
+/// Frequently leaked passwords and base words, compared after stripping
+/// leading/trailing digits and symbols (so "Password123!" matches "password").
+const COMMON_PASSWORDS: &[&str] = &[
+    "password",
+    "passw0rd",
+    "qwerty",
+    "qwertyuiop",
+    "asdfgh",
+    "asdfghjkl",
+    "zxcvbnm",
+    "letmein",
+    "welcome",
+    "iloveyou",
+    "admin",
+    "administrator",
+    "monkey",
+    "dragon",
+    "football",
+    "baseball",
+    "master",
+    "sunshine",
+    "princess",
+    "shadow",
+    "superman",
+    "trustno",
+    "starwars",
+    "whatever",
+    "freedom",
+    "secret",
+    "abc",
+    "abcdef",
+    "abcdefgh",
+    "login",
+    "changeme",
+    "default",
+    "meemi",
+    "kansio",
+    "salasana",
+];
+
+/// Checks that a password is reasonably hard to guess.
+///
+/// Returns `BadRequest` with a message describing the first failed rule.
+pub fn validate_strength(password: &str, username: &str) -> Result<(), AppError> {
+    let weak = |msg: &str| Err(AppError::BadRequest(msg.to_string()));
+    let len = password.chars().count();
+
+    if len < MIN_PASSWORD_LENGTH {
+        return weak(&format!(
+            "Password must be at least {MIN_PASSWORD_LENGTH} characters"
+        ));
+    }
+    if len > MAX_PASSWORD_LENGTH {
+        return weak(&format!(
+            "Password must be at most {MAX_PASSWORD_LENGTH} characters"
+        ));
+    }
+
+    let lower = password.to_lowercase();
+    let username = username.trim().to_lowercase();
+    if username.len() >= 3 && lower.contains(&username) {
+        return weak("Password must not contain your username");
+    }
+
+    let base = lower.trim_matches(|c: char| !c.is_alphabetic());
+    if COMMON_PASSWORDS.contains(&lower.as_str()) || COMMON_PASSWORDS.contains(&base) {
+        return weak("Password is too common");
+    }
+
+    let mut distinct: Vec<char> = password.chars().collect();
+    distinct.sort_unstable();
+    distinct.dedup();
+    if distinct.len() < MIN_DISTINCT_CHARS || is_sequence(&lower) {
+        return weak("Password is too repetitive or predictable");
+    }
+
+    if estimate_entropy_bits(password) < MIN_ENTROPY_BITS {
+        return weak(
+            "Password is too weak: make it longer or mix in uppercase letters, digits or symbols",
+        );
+    }
+
+    Ok(())
+}
+
+/// Upper-bound entropy estimate based on which character classes appear.
+fn estimate_entropy_bits(password: &str) -> f64 {
+    let has = |pred: fn(&char) -> bool| password.chars().any(|c| pred(&c));
+    let pool: u32 = [
+        (has(char::is_ascii_lowercase), 26),
+        (has(char::is_ascii_uppercase), 26),
+        (has(char::is_ascii_digit), 10),
+        (has(char::is_ascii_punctuation), 33),
+        (has(|c| c == &' '), 1),
+        (has(|c| !c.is_ascii()), 100),
+    ]
+    .iter()
+    .filter(|(present, _)| *present)
+    .map(|(_, size)| size)
+    .sum();
+
+    password.chars().count() as f64 * f64::from(pool.max(1)).log2()
+}
+
+/// True for runs like "12345678" or "abcdefgh" where every character
+/// steps by the same amount (including zero or backwards).
+fn is_sequence(password: &str) -> bool {
+    let chars: Vec<u32> = password.chars().map(u32::from).collect();
+    let step = i64::from(chars[1]) - i64::from(chars[0]);
+    step.abs() <= 1
+        && chars
+            .windows(2)
+            .all(|w| i64::from(w[1]) - i64::from(w[0]) == step)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_ok(password: &str) -> bool {
+        validate_strength(password, "alice").is_ok()
+    }
+
+    #[test]
+    fn rejects_short_passwords() {
+        assert!(!is_ok("aB3$x"));
+        assert!(!is_ok(""));
+    }
+
+    #[test]
+    fn rejects_common_passwords() {
+        assert!(!is_ok("password"));
+        assert!(!is_ok("password123"));
+        assert!(!is_ok("Password123!"));
+        assert!(!is_ok("qwertyuiop"));
+    }
+
+    #[test]
+    fn rejects_username_in_password() {
+        assert!(!is_ok("xyzAlice-2024"));
+        assert!(validate_strength("xyzAlice-2024", "bob").is_ok());
+    }
+
+    #[test]
+    fn rejects_repetitive_and_sequential() {
+        assert!(!is_ok("aaaaaaaaaaaa"));
+        assert!(!is_ok("abababababab"));
+        assert!(!is_ok("12345678901"));
+        assert!(!is_ok("abcdefghij"));
+    }
+
+    #[test]
+    fn rejects_low_entropy() {
+        // 8 lowercase letters ≈ 37.6 bits
+        assert!(!is_ok("mxkqzvbt"));
+        assert!(!is_ok("84629175"));
+    }
+
+    #[test]
+    fn accepts_strong_passwords() {
+        assert!(is_ok("correct-horse-battery"));
+        assert!(is_ok("Tr0ub4dor&3"));
+        assert!(is_ok("mxkqzvbtwp"));
+        assert!(is_ok("kissa ja koira"));
+    }
+
+    #[test]
+    fn rejects_overlong_passwords() {
+        assert!(!is_ok(&"aB3$".repeat(40)));
+    }
+}
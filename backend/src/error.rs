@@ -1,4 +1,4 @@
-use axum::http::StatusCode;
+use axum::http::{header, HeaderValue, StatusCode};
 use axum::response::{IntoResponse, Response};
 use axum::Json;
 use serde_json::json;
@@ -6,6 +6,7 @@ use serde_json::json;
 #[derive(Debug)]
 pub enum AppError {
     InvalidCredentials,
+    AccountLocked { retry_after_secs: i64 },
     Unauthorized,
     Forbidden,
     BadRequest(String),
@@ -19,6 +20,11 @@ impl std::fmt::Display for AppError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::InvalidCredentials => write!(f, "Invalid username or password"),
+            Self::AccountLocked { retry_after_secs } => write!(
+                f,
+                "Too many failed login attempts, try again in {} minutes",
+                (retry_after_secs + 59) / 60
+            ),
             Self::Unauthorized => write!(f, "Authentication required"),
             Self::Forbidden => write!(f, "Insufficient permissions"),
             Self::BadRequest(msg) => write!(f, "{msg}"),
@@ -34,6 +40,7 @@ impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let (status, message) = match &self {
             Self::InvalidCredentials => (StatusCode::UNAUTHORIZED, self.to_string()),
+            Self::AccountLocked { .. } => (StatusCode::TOO_MANY_REQUESTS, self.to_string()),
             Self::Unauthorized => (StatusCode::UNAUTHORIZED, self.to_string()),
             Self::Forbidden => (StatusCode::FORBIDDEN, self.to_string()),
             Self::BadRequest(_) => (StatusCode::BAD_REQUEST, self.to_string()),
@@ -52,7 +59,13 @@ impl IntoResponse for AppError {
             tracing::warn!(status = status.as_u16(), "{message}");
         }
 
-        (status, Json(json!({ "error": message }))).into_response()
+        let mut response = (status, Json(json!({ "error": message }))).into_response();
+        if let Self::AccountLocked { retry_after_secs } = self {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after_secs));
+        }
+        response
     }
 }
 
@@ -61,3 +74,13 @@ impl From<sqlx::Error> for AppError {
         Self::Database(e)
     }
 }
+
+impl From<ocr_rs::OcrError> for AppError {
+    fn from(e: ocr_rs::OcrError) -> Self {
+        if crate::ocr::is_image_error(&e) {
+            Self::BadRequest(format!("Cannot run OCR on this image: {e}"))
+        } else {
+            Self::Internal(format!("OCR failed: {e}"))
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Content negotiation for locally stored files.
+//!
+//! Large PNG originals are transcoded to WebP the first time a client that
+//! accepts WebP asks for them. The variant is cached next to the original
+//! and only served when it actually turned out smaller. JPEGs are left
+//! alone: the WebP encoder is lossless, so it practically never beats them.
+//! With S3 storage files are served straight from the bucket, so this only
+//! applies to local storage.
+//!
+//! AVIF is deliberately not negotiated. The `image` crate only encodes it
+//! lossily through rav1e, which would make the served file differ from the
+//! original, and encoding a full-size image takes seconds on first request.
+//! Clients that accept AVIF but not WebP get the original PNG.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::extract::{Request, State};
+use axum::http::{header, HeaderMap, HeaderValue, Method};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use tower_http::services::ServeFile;
+
+use crate::thumbnails;
+
+/// Originals smaller than this are served as-is.
+const MIN_OPTIMIZE_BYTES: u64 = 200 * 1024;
+
+/// Middleware for the `/api/files` service that swaps in the WebP variant
+/// of an original image when the client's `Accept` header allows it.
+pub async fn negotiate_webp(
+    State(upload_dir): State<Arc<PathBuf>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let key = req.uri().path().trim_start_matches('/').to_string();
+    if !is_transcodable(&key) {
+        return next.run(req).await;
+    }
+
+    let wants_webp =
+        matches!(*req.method(), Method::GET | Method::HEAD) && accepts_webp(req.headers());
+
+    let variant = if wants_webp {
+        optimized_variant(&upload_dir, &key).await
+    } else {
+        None
+    };
+
+    let mut response = match variant {
+        Some(path) => {
+            // ServeFile handles HEAD, ranges and conditional requests for us
+            let mut file_req = Request::new(Body::empty());
+            *file_req.method_mut() = req.method().clone();
+            *file_req.headers_mut() = req.headers().clone();
+            match ServeFile::new(&path).try_call(file_req).await {
+                Ok(res) => res.into_response(),
+                Err(e) => {
+                    tracing::warn!("failed to serve {}: {e}", path.display());
+                    next.run(req).await
+                }
+            }
+        }
+        None => next.run(req).await,
+    };
+
+    // Caches must key on Accept, since the same URL can yield either format
+    response
+        .headers_mut()
+        .append(header::VARY, HeaderValue::from_static("accept"));
+    response
+}
+
+/// Only originals are negotiated. Clipboard copies must stay PNG.
+fn is_transcodable(key: &str) -> bool {
+    if key.is_empty() || key.contains('/') || key.contains("..") {
+        return false;
+    }
+    let key = key.to_ascii_lowercase();
+    !key.ends_with("_clipboard.png") && key.ends_with(".png")
+}
+
+/// True if any `Accept` entry names `image/webp` with a non-zero quality.
+/// Wildcards are ignored: browsers that support WebP list it explicitly.
+/// `image/avif` is ignored too, see the module docs.
+fn accepts_webp(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|entry| {
+            let mut parts = entry.split(';').map(str::trim);
+            let media_type = parts.next().unwrap_or_default();
+            let quality = parts
+                .filter_map(|p| p.strip_prefix("q="))
+                .find_map(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            media_type.eq_ignore_ascii_case("image/webp") && quality > 0.0
+        })
+}
+
+/// Return the path of the WebP variant of `key` if it's worth serving,
+/// transcoding and caching it first if needed.
+async fn optimized_variant(upload_dir: &Path, key: &str) -> Option<PathBuf> {
+    let original = upload_dir.join(key);
+    let original_meta = tokio::fs::metadata(&original).await.ok()?;
+    if original_meta.len() < MIN_OPTIMIZE_BYTES {
+        return None;
+    }
+
+    let variant = upload_dir.join(thumbnails::optimized_key(key));
+    let cached = match tokio::fs::metadata(&variant).await {
+        // A replaced original is newer than its stale variant
+        Ok(meta) if meta.modified().ok() >= original_meta.modified().ok() => Some(meta.len()),
+        _ => None,
+    };
+
+    let variant_len = match cached {
+        Some(len) => len,
+        None => match transcode(&original, &variant).await {
+            Ok(len) => len,
+            Err(e) => {
+                tracing::warn!("failed to create WebP variant of {key}: {e}");
+                return None;
+            }
+        },
+    };
+
+    (variant_len < original_meta.len()).then_some(variant)
+}
+
+/// Transcode `original` into `variant`, returning the variant's size.
+async fn transcode(original: &Path, variant: &Path) -> Result<u64, String> {
+    let bytes = tokio::fs::read(original).await.map_err(|e| e.to_string())?;
+    let variant = variant.to_path_buf();
+    tokio::task::spawn_blocking(move || -> Result<u64, String> {
+        let webp = thumbnails::transcode_webp(&bytes).map_err(|e| e.to_string())?;
+        write_atomic(&variant, &webp).map_err(|e| e.to_string())?;
+        Ok(webp.len() as u64)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Write through a uniquely named temp file and rename it into place, so
+/// concurrent requests neither see a partial file nor clobber each other's.
+fn write_atomic(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let mut tmp = tempfile::NamedTempFile::new_in(dir)?;
+    tmp.write_all(contents)?;
+    tmp.persist(path)?;
+    Ok(())
+}
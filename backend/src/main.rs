@@ -1,8 +1,10 @@
 mod auth;
 mod config;
 mod error;
+mod files;
 mod models;
 pub mod ocr;
+mod reprocess;
 mod routes;
 mod storage;
 mod thumbnails;
@@ -11,10 +13,11 @@ mod video;
 use std::process;
 use std::sync::Arc;
 
-use axum::{extract::State, routing::get, Json, Router};
+use axum::{extract::State, middleware, routing::get, Json, Router};
 use clap::Parser;
 use config::Config;
 use ocr_rs::OcrEngine;
+use reprocess::ReprocessJob;
 use sqlx::PgPool;
 use storage::{LocalStorage, S3Storage, StorageBackend};
 use tower_http::cors::CorsLayer;
@@ -28,6 +31,7 @@ pub struct AppState {
     pub config: Arc<Config>,
     pub ocr: Option<Arc<OcrEngine>>,
     pub storage: StorageBackend,
+    pub reprocess: ReprocessJob,
 }
 
 #[derive(Parser)]
@@ -59,7 +63,29 @@ enum AdminAction {
 
 async fn health(State(state): State<AppState>) -> Json<serde_json::Value> {
     let row: (i32,) = sqlx::query_as("SELECT 1").fetch_one(&state.db).await.unwrap();
-    Json(serde_json::json!({ "status": "ok", "db": row.0 == 1 }))
+    // Lets operators check that the expected OCR models are mounted
+    let ocr = state.ocr.as_ref().map(|engine| {
+        let info = engine.model_info();
+        let shape = |shape: &ocr_rs::ModelShape| {
+            serde_json::json!({
+                "input_shape": shape.input_shape,
+                "output_shape": shape.output_shape,
+                "dynamic": shape.dynamic,
+            })
+        };
+        serde_json::json!({
+            "backend": engine.active_backend().map(|backend| format!("{backend:?}")),
+            "sessions": engine.pool_status().map(|pool| serde_json::json!({
+                "size": pool.size,
+                "det_available": pool.det_available,
+                "rec_available": pool.rec_available,
+            })),
+            "detection": shape(&info.detection),
+            "recognition": shape(&info.recognition),
+            "charset_size": info.charset_size,
+        })
+    });
+    Json(serde_json::json!({ "status": "ok", "db": row.0 == 1, "ocr": ocr }))
 }
 
 #[tokio::main]
@@ -106,8 +132,8 @@ async fn admin_set_password(db: &PgPool, username: &str) {
         process::exit(1);
     }
 
-    if password.len() < 8 {
-        eprintln!("Password must be at least 8 characters");
+    if let Err(e) = auth::password::validate_strength(&password, username) {
+        eprintln!("{e}");
         process::exit(1);
     }
 
@@ -186,6 +212,7 @@ async fn run_server() {
         config: Arc::new(config),
         ocr,
         storage,
+        reprocess: ReprocessJob::default(),
     };
 
     let mut app = Router::new()
@@ -197,7 +224,13 @@ async fn run_server() {
 
     // Serve uploaded files from local disk when using local storage
     if let Some(upload_dir) = state.storage.local_upload_dir() {
-        app = app.nest_service("/api/files", ServeDir::new(upload_dir));
+        let files = Router::new()
+            .fallback_service(ServeDir::new(upload_dir))
+            .layer(middleware::from_fn_with_state(
+                Arc::new(upload_dir.to_path_buf()),
+                files::negotiate_webp,
+            ));
+        app = app.nest_service("/api/files", files);
     }
 
     // Serve static frontend files when STATIC_DIR is set
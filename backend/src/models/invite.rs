@@ -3,12 +3,19 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use crate::models::user::UserRole;
+
 #[derive(Debug, Clone, FromRow, Serialize)]
 pub struct Invite {
     pub id: Uuid,
     pub code: String,
     pub created_by: Uuid,
+    /// The user who most recently redeemed this invite.
     pub used_by: Option<Uuid>,
+    pub max_uses: i32,
+    pub used_count: i32,
+    /// Role granted to users who register with this invite.
+    pub role: UserRole,
     pub expires_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
 }
@@ -16,4 +23,6 @@ pub struct Invite {
 #[derive(Debug, Deserialize)]
 pub struct CreateInviteRequest {
     pub expires_in_hours: Option<i64>,
+    pub max_uses: Option<i32>,
+    pub role: Option<UserRole>,
 }
@@ -29,6 +29,7 @@ pub struct Media {
     pub source_url: Option<String>,
     pub thumbnail_path: Option<String>,
     pub ocr_text: Option<String>,
+    pub ocr_confidence: Option<f32>,
     pub uploaded_by: Uuid,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -48,6 +49,8 @@ pub struct MediaResponse {
     pub width: Option<i32>,
     pub height: Option<i32>,
     pub ocr_text: Option<String>,
+    /// Mean OCR line confidence; None when the text was not produced by OCR.
+    pub ocr_confidence: Option<f32>,
     pub uploaded_by: Uuid,
     pub created_at: DateTime<Utc>,
     pub tags: Vec<String>,
@@ -91,6 +94,7 @@ impl Media {
             width: self.width,
             height: self.height,
             ocr_text: self.ocr_text,
+            ocr_confidence: self.ocr_confidence,
             uploaded_by: self.uploaded_by,
             created_at: self.created_at,
             tags,
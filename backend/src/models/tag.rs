@@ -7,3 +7,10 @@ pub struct Tag {
     pub id: Uuid,
     pub name: String,
 }
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct TagWithCount {
+    pub id: Uuid,
+    pub name: String,
+    pub media_count: i64,
+}
@@ -1,33 +1,79 @@
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
-use ocr_rs::OcrEngine;
+use ocr_rs::{
+    ModelBundle, OcrEngine, OcrEngineConfig, OcrError, ReadingDirection, TextNormalization,
+};
 use sqlx::PgPool;
 use uuid::Uuid;
 
-/// Try to initialize the OCR engine from model files in the given directory.
-/// Returns `None` if models are not found or initialization fails.
+/// Images scoring below this text likelihood skip OCR entirely.
+/// Kept low so captions on busy backgrounds are never skipped.
+const TEXT_LIKELIHOOD_THRESHOLD: f32 = 0.1;
+
+/// Give up on images that keep the OCR engine busy longer than this, so a single
+/// pathological upload can't hold a blocking worker indefinitely.
+const OCR_TIME_LIMIT: Duration = Duration::from_secs(30);
+
+/// Refuse images wider or taller than this before decoding them. A decoded
+/// 16k x 16k image already takes 1 GiB, anything bigger is not a meme.
+const MAX_IMAGE_DIMENSION: u32 = 16_384;
+
+/// Detection and recognition sessions, so this many uploads are recognized in
+/// parallel. Sessions run single-threaded to keep them from oversubscribing the CPU.
+const OCR_SESSIONS: usize = 4;
+
+/// Model bundle (models, charset and their default config in one tar archive)
+/// looked for in the model directory before the separate model files.
+const MODEL_BUNDLE: &str = "ocr-bundle.tar";
+
+/// Try to initialize the OCR engine from the model bundle or the model files in
+/// the given directory. Returns `None` if models are not found or initialization fails.
 pub fn init_engine(model_dir: &str) -> Option<Arc<OcrEngine>> {
     let dir = Path::new(model_dir);
-    let det_path = dir.join("PP-OCRv5_mobile_det.mnn");
-    let rec_path = dir.join("latin_PP-OCRv5_mobile_rec_infer.mnn");
-    let keys_path = dir.join("ppocr_keys_latin.txt");
-
-    for path in [&det_path, &rec_path, &keys_path] {
-        if !path.exists() {
-            tracing::warn!("OCR model file not found: {}", path.display());
-            return None;
+    let bundle_path = dir.join(MODEL_BUNDLE);
+
+    let engine = if bundle_path.exists() {
+        ModelBundle::open(&bundle_path).and_then(|mut bundle| {
+            bundle.config = configure(bundle.config);
+            bundle.build()
+        })
+    } else {
+        let det_path = dir.join("PP-OCRv5_mobile_det.mnn");
+        let rec_path = dir.join("latin_PP-OCRv5_mobile_rec_infer.mnn");
+        let keys_path = dir.join("ppocr_keys_latin.txt");
+
+        for path in [&det_path, &rec_path, &keys_path] {
+            if !path.exists() {
+                tracing::warn!("OCR model file not found: {}", path.display());
+                return None;
+            }
         }
-    }
 
-    match OcrEngine::new(
-        det_path.to_str().unwrap(),
-        rec_path.to_str().unwrap(),
-        keys_path.to_str().unwrap(),
-        None,
-    ) {
+        OcrEngine::new(
+            det_path.to_str().unwrap(),
+            rec_path.to_str().unwrap(),
+            keys_path.to_str().unwrap(),
+            Some(configure(OcrEngineConfig::new())),
+        )
+    };
+
+    match engine {
         Ok(engine) => {
-            tracing::info!("OCR engine initialized from {model_dir}");
+            match engine.active_backend() {
+                Some(backend) => {
+                    tracing::info!("OCR engine initialized from {model_dir} ({backend:?} backend)")
+                }
+                None => tracing::warn!(
+                    "OCR engine initialized from {model_dir}, but its backend could not be determined"
+                ),
+            }
+            // Run the models once so the first upload doesn't pay for MNN's lazy setup
+            match engine.warmup() {
+                Ok(timing) => tracing::info!("OCR engine warmed up in {:?}", timing.total()),
+                Err(e) => tracing::warn!("OCR engine warmup failed: {e}"),
+            }
             Some(Arc::new(engine))
         }
         Err(e) => {
@@ -37,34 +83,56 @@ pub fn init_engine(model_dir: &str) -> Option<Arc<OcrEngine>> {
     }
 }
 
-/// Run OCR on image bytes. Returns the recognized text or None on failure.
-pub fn recognize(engine: &OcrEngine, image_bytes: &[u8]) -> Option<String> {
-    let image = match image::load_from_memory(image_bytes) {
-        Ok(img) => img,
-        Err(e) => {
-            tracing::warn!("OCR: failed to decode image: {e}");
-            return None;
-        }
-    };
+/// Apply the limits and text handling uploads need on top of the models' config.
+fn configure(config: OcrEngineConfig) -> OcrEngineConfig {
+    // Stored text is searched, so fold compatibility forms and stray whitespace
+    let rec_options = config
+        .rec_options
+        .clone()
+        .with_normalize(TextNormalization::ALL);
+    config
+        .with_pool(OCR_SESSIONS)
+        .with_threads(1)
+        .with_max_inference_time(OCR_TIME_LIMIT)
+        .with_max_image_dimension(MAX_IMAGE_DIMENSION)
+        .with_rec_options(rec_options)
+        .with_reading_direction(ReadingDirection::Auto)
+}
 
-    match engine.recognize(&image) {
-        Ok(results) => {
-            let text: String = results
-                .iter()
-                .map(|r| r.text.as_str())
-                .collect::<Vec<_>>()
-                .join("\n");
-            if text.trim().is_empty() {
-                None
-            } else {
-                Some(text)
-            }
-        }
-        Err(e) => {
-            tracing::warn!("OCR recognition failed: {e}");
-            None
-        }
+/// Text recognized from an image.
+pub struct OcrText {
+    pub text: String,
+    /// Mean confidence over the recognized lines, 0.0-1.0.
+    pub confidence: f32,
+}
+
+/// Run OCR on image bytes. Returns `None` when no text was found.
+///
+/// Images that can't be decoded or are too large fail with
+/// [`OcrError::ImageDecode`] and [`OcrError::ImageTooLarge`], formats the
+/// `image` crate was built without with [`OcrError::UnsupportedFormat`].
+pub fn recognize(engine: &OcrEngine, image_bytes: &[u8]) -> Result<Option<OcrText>, OcrError> {
+    let image = engine.decode_image(image_bytes)?;
+
+    if !engine.should_ocr(&image, TEXT_LIKELIHOOD_THRESHOLD) {
+        tracing::debug!("OCR: skipping image unlikely to contain text");
+        return Ok(None);
+    }
+
+    let (text, confidence) = engine.recognize_to_string(&image)?;
+    if text.is_empty() {
+        return Ok(None);
     }
+    Ok(Some(OcrText { text, confidence }))
+}
+
+/// Whether OCR failed because of the image itself (undecodable, unsupported
+/// format or too large) rather than the engine, e.g. by running out of time.
+pub fn is_image_error(e: &OcrError) -> bool {
+    matches!(
+        e,
+        OcrError::ImageDecode(_) | OcrError::UnsupportedFormat(_) | OcrError::ImageTooLarge { .. }
+    )
 }
 
 /// Spawn a background task to run OCR on the given bytes and update the database.
@@ -79,19 +147,24 @@ pub fn spawn_ocr_task(
             tokio::task::spawn_blocking(move || recognize(&engine, &image_bytes)).await;
 
         match result {
-            Ok(Some(text)) => {
-                if let Err(e) = sqlx::query("UPDATE media SET ocr_text = $1 WHERE id = $2")
-                    .bind(&text)
-                    .bind(media_id)
-                    .execute(&db)
-                    .await
+            Ok(Ok(Some(ocr))) => {
+                if let Err(e) =
+                    sqlx::query("UPDATE media SET ocr_text = $1, ocr_confidence = $2 WHERE id = $3")
+                        .bind(&ocr.text)
+                        .bind(ocr.confidence)
+                        .bind(media_id)
+                        .execute(&db)
+                        .await
                 {
                     tracing::warn!("Failed to save OCR text for {media_id}: {e}");
                 }
             }
-            Ok(None) => {
+            Ok(Ok(None)) => {
                 tracing::debug!("No text detected by OCR for {media_id}");
             }
+            Ok(Err(e)) => {
+                tracing::warn!("OCR failed for {media_id}: {e}");
+            }
             Err(e) => {
                 tracing::warn!("OCR task panicked for {media_id}: {e}");
             }
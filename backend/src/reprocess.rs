@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use sqlx::PgPool;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::media::{Media, MediaType};
+use crate::storage::StorageBackend;
+
+/// Rows fetched from the database per batch.
+const REPROCESS_BATCH_SIZE: i64 = 100;
+/// Thumbnails regenerated in parallel (decoding/encoding is CPU and memory heavy).
+const REPROCESS_CONCURRENCY: usize = 4;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReprocessFailure {
+    pub media_id: Uuid,
+    pub error: String,
+}
+
+/// Progress of the latest thumbnail reprocessing job.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReprocessStatus {
+    pub running: bool,
+    pub media_type: Option<MediaType>,
+    pub total: i64,
+    pub processed: i64,
+    pub succeeded: i64,
+    pub failed: Vec<ReprocessFailure>,
+    /// Set when the job stopped early, e.g. because the database went away.
+    pub error: Option<String>,
+}
+
+/// Handle to the background thumbnail reprocessing job. Only one job runs at a
+/// time; its status stays readable after it finishes.
+#[derive(Clone, Default)]
+pub struct ReprocessJob {
+    status: Arc<Mutex<Option<ReprocessStatus>>>,
+}
+
+impl ReprocessJob {
+    pub fn status(&self) -> Option<ReprocessStatus> {
+        self.status.lock().unwrap().clone()
+    }
+
+    /// Start regenerating thumbnails for all media, optionally limited to one
+    /// media type. Fails with a conflict if a job is already running.
+    pub fn start(
+        &self,
+        db: PgPool,
+        storage: StorageBackend,
+        media_type: Option<MediaType>,
+    ) -> Result<ReprocessStatus, AppError> {
+        let started = {
+            let mut status = self.status.lock().unwrap();
+            if status.as_ref().is_some_and(|s| s.running) {
+                return Err(AppError::Conflict(
+                    "Thumbnail reprocessing is already running".into(),
+                ));
+            }
+            let started = ReprocessStatus {
+                running: true,
+                media_type: media_type.clone(),
+                total: 0,
+                processed: 0,
+                succeeded: 0,
+                failed: Vec::new(),
+                error: None,
+            };
+            *status = Some(started.clone());
+            started
+        };
+
+        let job = self.clone();
+        tokio::spawn(async move {
+            let result = job.run(&db, &storage, media_type).await;
+            job.update(|status| {
+                status.running = false;
+                if let Err(e) = result {
+                    tracing::error!("Thumbnail reprocessing stopped: {e}");
+                    status.error = Some(e.to_string());
+                }
+            });
+        });
+
+        Ok(started)
+    }
+
+    fn update(&self, f: impl FnOnce(&mut ReprocessStatus)) {
+        if let Some(status) = self.status.lock().unwrap().as_mut() {
+            f(status);
+        }
+    }
+
+    async fn run(
+        &self,
+        db: &PgPool,
+        storage: &StorageBackend,
+        media_type: Option<MediaType>,
+    ) -> Result<(), AppError> {
+        let total: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM media WHERE $1::media_type IS NULL OR media_type = $1",
+        )
+        .bind(&media_type)
+        .fetch_one(db)
+        .await?;
+        self.update(|status| status.total = total);
+
+        tracing::info!("Reprocessing thumbnails for {total} media (type: {media_type:?})");
+
+        let semaphore = Arc::new(Semaphore::new(REPROCESS_CONCURRENCY));
+        let mut last_id: Option<Uuid> = None;
+
+        // Keyset pagination over id so rows aren't all loaded at once
+        loop {
+            let batch = sqlx::query_as::<_, Media>(
+                "SELECT * FROM media
+                 WHERE ($1::media_type IS NULL OR media_type = $1)
+                   AND ($2::uuid IS NULL OR id > $2)
+                 ORDER BY id
+                 LIMIT $3",
+            )
+            .bind(&media_type)
+            .bind(last_id)
+            .bind(REPROCESS_BATCH_SIZE)
+            .fetch_all(db)
+            .await?;
+
+            let Some(last) = batch.last() else {
+                break;
+            };
+            last_id = Some(last.id);
+
+            let mut tasks = JoinSet::new();
+            let mut task_media = HashMap::new();
+            for media in batch {
+                let permit = semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .map_err(|e| AppError::Internal(format!("Semaphore closed: {e}")))?;
+                let storage = storage.clone();
+                let media_id = media.id;
+                let handle = tasks.spawn(async move {
+                    let _permit = permit;
+                    crate::thumbnails::regenerate(&storage, &media).await
+                });
+                task_media.insert(handle.id(), media_id);
+            }
+
+            while let Some(joined) = tasks.join_next_with_id().await {
+                let (media_id, result) = match joined {
+                    Ok((task_id, result)) => {
+                        (task_media[&task_id], result.map_err(|e| e.to_string()))
+                    }
+                    Err(e) => (
+                        task_media[&e.id()],
+                        Err(format!("Thumbnail task panicked: {e}")),
+                    ),
+                };
+                self.update(|status| {
+                    status.processed += 1;
+                    match result {
+                        Ok(()) => status.succeeded += 1,
+                        Err(error) => {
+                            tracing::warn!(
+                                "Failed to reprocess thumbnails for {media_id}: {error}"
+                            );
+                            status.failed.push(ReprocessFailure { media_id, error });
+                        }
+                    }
+                });
+            }
+
+            if let Some(status) = self.status() {
+                tracing::info!("Reprocessed thumbnails: {}/{total}", status.processed);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::Deserialize;
+
+use crate::auth::middleware::AuthUser;
+use crate::error::AppError;
+use crate::models::media::MediaType;
+use crate::reprocess::ReprocessStatus;
+use crate::AppState;
+
+pub fn router() -> Router<AppState> {
+    Router::new().route(
+        "/api/admin/reprocess-thumbnails",
+        post(reprocess_thumbnails).get(reprocess_status),
+    )
+}
+
+#[derive(Debug, Deserialize)]
+struct ReprocessParams {
+    media_type: Option<MediaType>,
+}
+
+/// Start regenerating thumbnails for all media in the background, optionally
+/// limited to one media type. Progress is polled from `reprocess_status`.
+async fn reprocess_thumbnails(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Query(params): Query<ReprocessParams>,
+) -> Result<(StatusCode, Json<ReprocessStatus>), AppError> {
+    if !auth.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let status = state
+        .reprocess
+        .start(state.db, state.storage, params.media_type)?;
+    Ok((StatusCode::ACCEPTED, Json(status)))
+}
+
+/// Status of the latest reprocessing job, or `null` if none has been started.
+async fn reprocess_status(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<Option<ReprocessStatus>>, AppError> {
+    if !auth.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    Ok(Json(state.reprocess.status()))
+}
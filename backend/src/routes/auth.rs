@@ -5,7 +5,7 @@ use axum_extra::extract::cookie::{Cookie, SameSite};
 use axum_extra::extract::CookieJar;
 
 use crate::auth::middleware::AuthUser;
-use crate::auth::{jwt, password};
+use crate::auth::{jwt, lockout, password};
 use crate::error::AppError;
 use crate::models::user::{LoginRequest, RegisterRequest, User, UserResponse, UserRole};
 use crate::AppState;
@@ -25,15 +25,21 @@ async fn register(
 ) -> Result<(CookieJar, Json<UserResponse>), AppError> {
     let username = body.username.trim().to_lowercase();
 
-    if username.is_empty() || body.password.len() < 8 {
-        return Err(AppError::BadRequest(
-            "Username required and password must be at least 8 characters".into(),
-        ));
+    if username.is_empty() {
+        return Err(AppError::BadRequest("Username required".into()));
     }
 
+    password::validate_strength(&body.password, &username)?;
+
+    let password_hash = password::hash_password(&body.password)?;
+
+    // The invite row is locked until commit so concurrent registrations
+    // cannot push a multi-use invite past its limit.
+    let mut tx = state.db.begin().await?;
+
     // Check if any users exist (first user = admin, no invite needed)
     let (user_count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM users")
-        .fetch_one(&state.db)
+        .fetch_one(&mut *tx)
         .await?;
 
     let role;
@@ -47,31 +53,31 @@ async fn register(
             .as_deref()
             .ok_or_else(|| AppError::BadRequest("Invite code required".into()))?;
 
-        let row = sqlx::query_as::<_, (uuid::Uuid,)>(
-            "SELECT id FROM invites WHERE code = $1 AND used_by IS NULL AND expires_at > now()",
+        let (id, invite_role) = sqlx::query_as::<_, (uuid::Uuid, UserRole)>(
+            "SELECT id, role FROM invites
+             WHERE code = $1 AND used_count < max_uses AND expires_at > now()
+             FOR UPDATE",
         )
         .bind(code)
-        .fetch_optional(&state.db)
+        .fetch_optional(&mut *tx)
         .await?
         .ok_or_else(|| AppError::BadRequest("Invalid or expired invite code".into()))?;
 
-        invite_id = Some(row.0);
-        role = UserRole::Member;
+        invite_id = Some(id);
+        role = invite_role;
     }
 
     // Check username uniqueness
     let (existing,): (i64,) =
         sqlx::query_as("SELECT COUNT(*) FROM users WHERE username = $1")
             .bind(&username)
-            .fetch_one(&state.db)
+            .fetch_one(&mut *tx)
             .await?;
 
     if existing > 0 {
         return Err(AppError::Conflict("Username already taken".into()));
     }
 
-    let password_hash = password::hash_password(&body.password)?;
-
     let user = sqlx::query_as::<_, User>(
         "INSERT INTO users (username, password_hash, role)
          VALUES ($1, $2, $3)
@@ -80,18 +86,20 @@ async fn register(
     .bind(&username)
     .bind(&password_hash)
     .bind(&role)
-    .fetch_one(&state.db)
+    .fetch_one(&mut *tx)
     .await?;
 
-    // Mark invite as used
+    // Count the redemption
     if let Some(inv_id) = invite_id {
-        sqlx::query("UPDATE invites SET used_by = $1 WHERE id = $2")
+        sqlx::query("UPDATE invites SET used_by = $1, used_count = used_count + 1 WHERE id = $2")
             .bind(user.id)
             .bind(inv_id)
-            .execute(&state.db)
+            .execute(&mut *tx)
             .await?;
     }
 
+    tx.commit().await?;
+
     let token = jwt::create_token(user.id, &user.username, &user.role, &state.config.jwt_secret)?;
     let cookie = build_auth_cookie(token);
 
@@ -105,18 +113,24 @@ async fn login(
 ) -> Result<(CookieJar, Json<UserResponse>), AppError> {
     let username = body.username.trim().to_lowercase();
 
+    lockout::begin_attempt(&state.db, &username).await?;
+
     let user = sqlx::query_as::<_, User>(
         "SELECT id, username, password_hash, role, created_at, updated_at
          FROM users WHERE username = $1",
     )
     .bind(&username)
     .fetch_optional(&state.db)
-    .await?
-    .ok_or(AppError::InvalidCredentials)?;
+    .await?;
 
-    if !password::verify_password(&body.password, &user.password_hash)? {
-        return Err(AppError::InvalidCredentials);
-    }
+    // The attempt has already been counted. Unknown usernames count as
+    // failures too, so lockouts don't reveal which accounts exist.
+    let user = match user {
+        Some(user) if password::verify_password(&body.password, &user.password_hash)? => user,
+        _ => return Err(AppError::InvalidCredentials),
+    };
+
+    lockout::reset(&state.db, &username).await?;
 
     let token = jwt::create_token(user.id, &user.username, &user.role, &state.config.jwt_secret)?;
     let cookie = build_auth_cookie(token);
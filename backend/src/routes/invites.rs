@@ -6,8 +6,12 @@ use rand::Rng;
 use crate::auth::middleware::AuthUser;
 use crate::error::AppError;
 use crate::models::invite::{CreateInviteRequest, Invite};
+use crate::models::user::UserRole;
 use crate::AppState;
 
+/// Upper bound for how many registrations a single invite can allow.
+const MAX_INVITE_USES: i32 = 100;
+
 pub fn router() -> Router<AppState> {
     Router::new().route("/api/invites", post(create_invite).get(list_invites))
 }
@@ -37,15 +41,25 @@ async fn create_invite(
 
     let code = generate_invite_code();
     let expires_in_hours = body.expires_in_hours.unwrap_or(72) as f64;
+    let max_uses = body.max_uses.unwrap_or(1);
+    let role = body.role.unwrap_or(UserRole::Member);
+
+    if !(1..=MAX_INVITE_USES).contains(&max_uses) {
+        return Err(AppError::BadRequest(format!(
+            "max_uses must be between 1 and {MAX_INVITE_USES}"
+        )));
+    }
 
     let invite = sqlx::query_as::<_, Invite>(
-        "INSERT INTO invites (code, created_by, expires_at)
-         VALUES ($1, $2, now() + ($3 || ' hours')::interval)
-         RETURNING id, code, created_by, used_by, expires_at, created_at",
+        "INSERT INTO invites (code, created_by, expires_at, max_uses, role)
+         VALUES ($1, $2, now() + ($3 || ' hours')::interval, $4, $5)
+         RETURNING id, code, created_by, used_by, max_uses, used_count, role, expires_at, created_at",
     )
     .bind(&code)
     .bind(auth.user_id)
     .bind(expires_in_hours.to_string())
+    .bind(max_uses)
+    .bind(&role)
     .fetch_one(&state.db)
     .await?;
 
@@ -61,7 +75,7 @@ async fn list_invites(
     }
 
     let invites = sqlx::query_as::<_, Invite>(
-        "SELECT id, code, created_by, used_by, expires_at, created_at
+        "SELECT id, code, created_by, used_by, max_uses, used_count, role, expires_at, created_at
          FROM invites ORDER BY created_at DESC",
     )
     .fetch_all(&state.db)
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::Cursor;
 
 use axum::extract::{DefaultBodyLimit, Multipart, Query, State};
@@ -6,7 +6,7 @@ use axum::http::StatusCode;
 use axum::routing::{get, post, put};
 use axum::{Json, Router};
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -60,6 +60,7 @@ pub fn router() -> Router<AppState> {
             get(get_media).patch(update_media).delete(delete_media),
         )
         .route("/api/media/{id}/tags", put(set_tags))
+        .route("/api/media/tags/bulk", post(bulk_tags))
         .route("/api/media/{id}/regenerate-thumbnail", post(regenerate_thumbnail))
         .route("/api/media/{id}/run-ocr", post(run_ocr))
 }
@@ -414,6 +415,7 @@ async fn update_media(
            name = CASE WHEN $1 THEN $2 ELSE name END,
            description = CASE WHEN $3 THEN $4 ELSE description END,
            ocr_text = CASE WHEN $5 THEN $6 ELSE ocr_text END,
+           ocr_confidence = CASE WHEN $5 THEN NULL ELSE ocr_confidence END,
            updated_at = NOW()
          WHERE id = $7 RETURNING *",
     )
@@ -571,7 +573,7 @@ async fn replace_file(
 
     let media = sqlx::query_as::<_, Media>(
         "UPDATE media SET file_path = $1, file_size = $2, mime_type = $3, media_type = $4,
-         width = $5, height = $6, ocr_text = NULL, updated_at = NOW()
+         width = $5, height = $6, ocr_text = NULL, ocr_confidence = NULL, updated_at = NOW()
          WHERE id = $7 RETURNING *",
     )
     .bind(&file_name)
@@ -632,61 +634,7 @@ async fn regenerate_thumbnail(
         .await?
         .ok_or_else(|| AppError::NotFound("Media not found".into()))?;
 
-    // Delete existing thumbnails
-    for key in crate::thumbnails::thumbnail_keys(&media.file_path) {
-        state.storage.delete(&key).await;
-    }
-
-    let thumb_stem = media
-        .file_path
-        .rsplit_once('.')
-        .map(|(s, _)| s.to_string())
-        .unwrap_or_else(|| media.file_path.clone());
-
-    // Regenerate
-    if media.media_type != MediaType::Video {
-        let bytes = state.storage.get(&media.file_path).await?;
-        let result = tokio::task::spawn_blocking(move || {
-            crate::thumbnails::generate(&bytes)
-        })
-        .await;
-        match result {
-            Ok(Ok((thumb_bytes, clipboard_bytes))) => {
-                let thumb_key = format!("{thumb_stem}_thumb.webp");
-                let clipboard_key = format!("{thumb_stem}_clipboard.png");
-                state.storage.put(&thumb_key, &thumb_bytes, "image/webp").await?;
-                state.storage.put(&clipboard_key, &clipboard_bytes, "image/png").await?;
-            }
-            Ok(Err(e)) => return Err(AppError::Internal(format!("Thumbnail generation failed: {e}"))),
-            Err(e) => return Err(AppError::Internal(format!("Thumbnail task panicked: {e}"))),
-        }
-    } else {
-        // Video: write to temp file for FFmpeg frame extraction
-        let bytes = state.storage.get(&media.file_path).await?;
-        let tmp_dir = tempfile::tempdir()
-            .map_err(|e| AppError::Internal(format!("Failed to create temp dir: {e}")))?;
-        let tmp_path = tmp_dir.path().join(&media.file_path);
-        tokio::fs::write(&tmp_path, &bytes)
-            .await
-            .map_err(|e| AppError::Internal(format!("Failed to write temp file: {e}")))?;
-        match crate::video::extract_frame(&tmp_path).await {
-            Ok(frame_bytes) => {
-                let result = tokio::task::spawn_blocking(move || {
-                    crate::thumbnails::generate_gallery_thumb(&frame_bytes)
-                })
-                .await;
-                match result {
-                    Ok(Ok(thumb_bytes)) => {
-                        let thumb_key = format!("{thumb_stem}_thumb.webp");
-                        state.storage.put(&thumb_key, &thumb_bytes, "image/webp").await?;
-                    }
-                    Ok(Err(e)) => return Err(AppError::Internal(format!("Video thumbnail generation failed: {e}"))),
-                    Err(e) => return Err(AppError::Internal(format!("Video thumbnail task panicked: {e}"))),
-                }
-            }
-            Err(e) => return Err(AppError::Internal(format!("Video frame extraction failed: {e}"))),
-        }
-    }
+    crate::thumbnails::regenerate(&state.storage, &media).await?;
 
     let tags = fetch_tags(&state.db, media.id).await?;
     Ok(Json(media.into_response(tags, &state.storage)))
@@ -723,14 +671,27 @@ async fn run_ocr(
         .map_err(|e| AppError::Internal(format!("Failed to read file for OCR: {e}")))?;
 
     let engine = ocr_engine.clone();
-    let ocr_text = tokio::task::spawn_blocking(move || crate::ocr::recognize(&engine, &bytes))
+    let ocr = tokio::task::spawn_blocking(move || crate::ocr::recognize(&engine, &bytes))
         .await
         .map_err(|e| AppError::Internal(format!("OCR task panicked: {e}")))?;
+    // Only a bad image is the caller's problem. Anything else, like hitting the
+    // time limit, clears the OCR text as if none was found.
+    let (ocr_text, ocr_confidence) = match ocr {
+        Ok(Some(ocr)) => (Some(ocr.text), Some(ocr.confidence)),
+        Ok(None) => (None, None),
+        Err(e) if crate::ocr::is_image_error(&e) => return Err(e.into()),
+        Err(e) => {
+            tracing::warn!("OCR failed for {id}: {e}");
+            (None, None)
+        }
+    };
 
     let media = sqlx::query_as::<_, Media>(
-        "UPDATE media SET ocr_text = $1, updated_at = NOW() WHERE id = $2 RETURNING *",
+        "UPDATE media SET ocr_text = $1, ocr_confidence = $2, updated_at = NOW()
+         WHERE id = $3 RETURNING *",
     )
     .bind(&ocr_text)
+    .bind(ocr_confidence)
     .bind(id)
     .fetch_one(&state.db)
     .await?;
@@ -767,6 +728,151 @@ async fn set_tags(
     Ok(Json(media.into_response(tags, &state.storage)))
 }
 
+const MAX_BULK_TAG_ITEMS: usize = 500;
+
+#[derive(Debug, Deserialize)]
+struct BulkTagsRequest {
+    media_ids: Vec<Uuid>,
+    #[serde(default)]
+    add: Vec<String>,
+    #[serde(default)]
+    remove: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct BulkTagResult {
+    media_id: Uuid,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct BulkTagsResponse {
+    results: Vec<BulkTagResult>,
+}
+
+fn validate_tags_dedup(names: &[String]) -> Result<Vec<String>, AppError> {
+    let mut validated = Vec::with_capacity(names.len());
+    for name in names {
+        let name = validate_tag(name)?;
+        if !validated.contains(&name) {
+            validated.push(name);
+        }
+    }
+    Ok(validated)
+}
+
+/// Add and remove tags on many media items in one transaction.
+async fn bulk_tags(
+    State(state): State<AppState>,
+    _auth: AuthUser,
+    Json(body): Json<BulkTagsRequest>,
+) -> Result<Json<BulkTagsResponse>, AppError> {
+    if body.media_ids.is_empty() {
+        return Err(AppError::BadRequest("No media selected".into()));
+    }
+    if body.media_ids.len() > MAX_BULK_TAG_ITEMS {
+        return Err(AppError::BadRequest(format!(
+            "Cannot tag more than {MAX_BULK_TAG_ITEMS} items at once"
+        )));
+    }
+
+    let add = validate_tags_dedup(&body.add)?;
+    let remove = validate_tags_dedup(&body.remove)?;
+    if let Some(tag) = add.iter().find(|t| remove.contains(t)) {
+        return Err(AppError::BadRequest(format!(
+            "Tag '{tag}' cannot be both added and removed"
+        )));
+    }
+
+    let mut media_ids: Vec<Uuid> = Vec::with_capacity(body.media_ids.len());
+    for id in body.media_ids {
+        if !media_ids.contains(&id) {
+            media_ids.push(id);
+        }
+    }
+
+    let mut tx = state.db.begin().await?;
+
+    let existing: HashSet<Uuid> = sqlx::query_scalar::<_, Uuid>(
+        "SELECT id FROM media WHERE id = ANY($1)",
+    )
+    .bind(&media_ids)
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .collect();
+    let found: Vec<Uuid> = media_ids
+        .iter()
+        .copied()
+        .filter(|id| existing.contains(id))
+        .collect();
+
+    if !found.is_empty() && !remove.is_empty() {
+        sqlx::query(
+            "DELETE FROM media_tags mt USING tags t
+             WHERE mt.tag_id = t.id AND mt.media_id = ANY($1) AND t.name = ANY($2)",
+        )
+        .bind(&found)
+        .bind(&remove)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    if !found.is_empty() && !add.is_empty() {
+        let mut tag_ids: Vec<Uuid> = Vec::with_capacity(add.len());
+        for name in &add {
+            let tag_id: Uuid = sqlx::query_scalar(
+                "INSERT INTO tags (name) VALUES ($1)
+                 ON CONFLICT (name) DO UPDATE SET name = EXCLUDED.name
+                 RETURNING id",
+            )
+            .bind(name)
+            .fetch_one(&mut *tx)
+            .await?;
+            tag_ids.push(tag_id);
+        }
+
+        sqlx::query(
+            "INSERT INTO media_tags (media_id, tag_id)
+             SELECT m.id, t.id FROM UNNEST($1::uuid[]) AS m(id) CROSS JOIN UNNEST($2::uuid[]) AS t(id)
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(&found)
+        .bind(&tag_ids)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    let mut tags_map = fetch_tags_batch(&state.db, &found).await?;
+    let results = media_ids
+        .into_iter()
+        .map(|media_id| {
+            if existing.contains(&media_id) {
+                BulkTagResult {
+                    media_id,
+                    ok: true,
+                    error: None,
+                    tags: tags_map.remove(&media_id).unwrap_or_default(),
+                }
+            } else {
+                BulkTagResult {
+                    media_id,
+                    ok: false,
+                    error: Some("Media not found".into()),
+                    tags: vec![],
+                }
+            }
+        })
+        .collect();
+
+    Ok(Json(BulkTagsResponse { results }))
+}
+
 #[derive(Debug, Deserialize)]
 struct ListMediaParams {
     cursor: Option<DateTime<Utc>>,
@@ -1,3 +1,4 @@
+pub mod admin;
 pub mod auth;
 pub mod invites;
 pub mod media;
@@ -9,6 +10,7 @@ use crate::AppState;
 
 pub fn api_router(enable_test_routes: bool) -> Router<AppState> {
     let router = Router::new()
+        .merge(admin::router())
         .merge(auth::router())
         .merge(invites::router())
         .merge(media::router())
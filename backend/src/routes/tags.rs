@@ -5,11 +5,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::auth::middleware::AuthUser;
 use crate::error::AppError;
-use crate::models::tag::Tag;
+use crate::models::tag::{Tag, TagWithCount};
 use crate::AppState;
 
 pub fn router() -> Router<AppState> {
-    Router::new().route("/api/tags", get(search_tags))
+    Router::new()
+        .route("/api/tags", get(search_tags))
+        .route("/api/tags/popular", get(popular_tags))
 }
 
 #[derive(Debug, Deserialize)]
@@ -52,3 +54,54 @@ async fn search_tags(
 
     Ok(Json(SearchTagsResponse { tags }))
 }
+
+const DEFAULT_POPULAR_LIMIT: i64 = 50;
+const MAX_POPULAR_LIMIT: i64 = 200;
+
+#[derive(Debug, Deserialize)]
+struct PopularTagsParams {
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+struct PopularTagsResponse {
+    tags: Vec<TagWithCount>,
+    next_offset: Option<i64>,
+}
+
+/// All tags with their media counts, most used first.
+async fn popular_tags(
+    State(state): State<AppState>,
+    _auth: AuthUser,
+    Query(params): Query<PopularTagsParams>,
+) -> Result<Json<PopularTagsResponse>, AppError> {
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_POPULAR_LIMIT)
+        .clamp(1, MAX_POPULAR_LIMIT);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    // Fetch one extra row to know whether there's another page
+    let mut tags = sqlx::query_as::<_, TagWithCount>(
+        "SELECT t.id, t.name, COUNT(mt.media_id) AS media_count
+         FROM tags t
+         LEFT JOIN media_tags mt ON mt.tag_id = t.id
+         GROUP BY t.id, t.name
+         ORDER BY media_count DESC, t.name
+         LIMIT $1 OFFSET $2",
+    )
+    .bind(limit + 1)
+    .bind(offset)
+    .fetch_all(&state.db)
+    .await?;
+
+    let next_offset = if tags.len() as i64 > limit {
+        tags.truncate(limit as usize);
+        Some(offset + limit)
+    } else {
+        None
+    };
+
+    Ok(Json(PopularTagsResponse { tags, next_offset }))
+}
@@ -4,6 +4,8 @@ use image::imageops::FilterType;
 use image::{DynamicImage, ImageFormat, ImageReader};
 
 use crate::error::AppError;
+use crate::models::media::{Media, MediaType};
+use crate::storage::StorageBackend;
 
 const THUMB_MAX_DIM: u32 = 600;
 const CLIPBOARD_MAX_DIM: u32 = 1024;
@@ -65,15 +67,93 @@ pub fn generate_gallery_thumb(bytes: &[u8]) -> Result<Vec<u8>, AppError> {
     encode_webp(&thumb)
 }
 
-/// Return the thumbnail storage keys derived from the original filename.
-/// Used for cleanup during delete/replace.
-pub fn thumbnail_keys(file_name: &str) -> [String; 2] {
-    let stem = std::path::Path::new(file_name)
+/// Re-encode a full-size image as lossless WebP, keeping its dimensions.
+/// Used for the optimized PNG originals served to clients that accept WebP.
+pub fn transcode_webp(bytes: &[u8]) -> Result<Vec<u8>, AppError> {
+    let img = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| AppError::Internal(format!("Failed to detect image format: {e}")))?
+        .decode()
+        .map_err(|e| AppError::Internal(format!("Failed to decode image: {e}")))?;
+
+    encode_webp(&img)
+}
+
+/// Storage key of the cached WebP variant of an original image.
+pub fn optimized_key(file_name: &str) -> String {
+    format!("{}_optimized.webp", file_stem(file_name))
+}
+
+fn file_stem(file_name: &str) -> &str {
+    std::path::Path::new(file_name)
         .file_stem()
         .and_then(|s| s.to_str())
-        .unwrap_or(file_name);
+        .unwrap_or(file_name)
+}
+
+/// Return the storage keys of all files derived from the original filename.
+/// Used for cleanup during delete/replace.
+pub fn thumbnail_keys(file_name: &str) -> [String; 3] {
+    let stem = file_stem(file_name);
     [
         format!("{stem}_thumb.webp"),
         format!("{stem}_clipboard.png"),
+        optimized_key(file_name),
     ]
 }
+
+/// Delete and regenerate the thumbnails of a stored media item.
+pub async fn regenerate(storage: &StorageBackend, media: &Media) -> Result<(), AppError> {
+    // Delete existing thumbnails
+    for key in thumbnail_keys(&media.file_path) {
+        storage.delete(&key).await;
+    }
+
+    let thumb_stem = media
+        .file_path
+        .rsplit_once('.')
+        .map(|(s, _)| s.to_string())
+        .unwrap_or_else(|| media.file_path.clone());
+
+    // Regenerate
+    if media.media_type != MediaType::Video {
+        let bytes = storage.get(&media.file_path).await?;
+        let result = tokio::task::spawn_blocking(move || generate(&bytes)).await;
+        match result {
+            Ok(Ok((thumb_bytes, clipboard_bytes))) => {
+                let thumb_key = format!("{thumb_stem}_thumb.webp");
+                let clipboard_key = format!("{thumb_stem}_clipboard.png");
+                storage.put(&thumb_key, &thumb_bytes, "image/webp").await?;
+                storage.put(&clipboard_key, &clipboard_bytes, "image/png").await?;
+            }
+            Ok(Err(e)) => return Err(AppError::Internal(format!("Thumbnail generation failed: {e}"))),
+            Err(e) => return Err(AppError::Internal(format!("Thumbnail task panicked: {e}"))),
+        }
+    } else {
+        // Video: write to temp file for FFmpeg frame extraction
+        let bytes = storage.get(&media.file_path).await?;
+        let tmp_dir = tempfile::tempdir()
+            .map_err(|e| AppError::Internal(format!("Failed to create temp dir: {e}")))?;
+        let tmp_path = tmp_dir.path().join(&media.file_path);
+        tokio::fs::write(&tmp_path, &bytes)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to write temp file: {e}")))?;
+        match crate::video::extract_frame(&tmp_path).await {
+            Ok(frame_bytes) => {
+                let result =
+                    tokio::task::spawn_blocking(move || generate_gallery_thumb(&frame_bytes)).await;
+                match result {
+                    Ok(Ok(thumb_bytes)) => {
+                        let thumb_key = format!("{thumb_stem}_thumb.webp");
+                        storage.put(&thumb_key, &thumb_bytes, "image/webp").await?;
+                    }
+                    Ok(Err(e)) => return Err(AppError::Internal(format!("Video thumbnail generation failed: {e}"))),
+                    Err(e) => return Err(AppError::Internal(format!("Video thumbnail task panicked: {e}"))),
+                }
+            }
+            Err(e) => return Err(AppError::Internal(format!("Video frame extraction failed: {e}"))),
+        }
+    }
+
+    Ok(())
+}
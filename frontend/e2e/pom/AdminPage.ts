@@ -4,17 +4,43 @@ export class AdminPage {
   readonly heading: Locator;
   readonly newInviteButton: Locator;
   readonly inviteCode: Locator;
+  readonly maxUsesInput: Locator;
+  readonly inviteRoleSelect: Locator;
+  readonly reprocessTypeSelect: Locator;
+  readonly reprocessButton: Locator;
+  readonly reprocessResult: Locator;
+  readonly reprocessProgress: Locator;
 
   constructor(page: Page) {
     this.heading = page.getByRole('heading', { name: 'Admin' });
     this.newInviteButton = page.getByRole('button', { name: 'New invite' });
     this.inviteCode = page.locator('code').first();
+    this.maxUsesInput = page.getByLabel('Max uses');
+    this.inviteRoleSelect = page.getByLabel('Invite role');
+    this.reprocessTypeSelect = page.getByLabel('Media type');
+    this.reprocessButton = page.getByTestId('reprocess-button');
+    this.reprocessResult = page.getByTestId('reprocess-result');
+    this.reprocessProgress = page.getByTestId('reprocess-progress');
   }
 
-  async createInvite(): Promise<string> {
+  async createInvite(options: { maxUses?: number; role?: 'admin' | 'member' } = {}): Promise<string> {
+    if (options.maxUses !== undefined) {
+      await this.maxUsesInput.fill(String(options.maxUses));
+    }
+    if (options.role) {
+      await this.inviteRoleSelect.selectOption(options.role);
+    }
     await this.newInviteButton.click();
     await expect(this.inviteCode).toBeVisible();
     const code = await this.inviteCode.textContent();
     return code!;
   }
+
+  async reprocessThumbnails(mediaType = '') {
+    await this.reprocessTypeSelect.selectOption(mediaType);
+    await this.reprocessButton.click();
+    // The job runs in the background; the button is re-enabled once it finishes
+    await expect(this.reprocessButton).toBeEnabled();
+    await expect(this.reprocessResult).toBeVisible();
+  }
 }
@@ -9,12 +9,20 @@ export class BrowsePage {
   readonly addFilterTagButton: Locator;
   readonly addFilterTagInput: Locator;
   readonly filterTagChips: Locator;
+  readonly popularTagSuggestions: Locator;
+  readonly popularTagCounts: Locator;
   readonly noMatchText: Locator;
   readonly typeFilterAll: Locator;
   readonly typeFilterPictures: Locator;
   readonly typeFilterGifs: Locator;
   readonly typeFilterVideos: Locator;
   readonly searchInput: Locator;
+  readonly selectModeButton: Locator;
+  readonly bulkTagBar: Locator;
+  readonly bulkSelectedCount: Locator;
+  readonly bulkAddTagsInput: Locator;
+  readonly bulkRemoveTagsInput: Locator;
+  readonly bulkApplyButton: Locator;
 
   constructor(page: Page) {
     this.page = page;
@@ -25,12 +33,20 @@ export class BrowsePage {
     this.addFilterTagButton = page.getByTestId('add-filter-tag-button');
     this.addFilterTagInput = page.getByTestId('add-filter-tag-input');
     this.filterTagChips = page.getByTestId('filter-tag-chip');
+    this.popularTagSuggestions = page.getByTestId('popular-tag-suggestions').getByRole('option');
+    this.popularTagCounts = page.getByTestId('popular-tag-count');
     this.noMatchText = page.getByText('No media matches the selected filters');
     this.typeFilterAll = page.getByTestId('type-filter-all');
     this.typeFilterPictures = page.getByTestId('type-filter-image');
     this.typeFilterGifs = page.getByTestId('type-filter-gif');
     this.typeFilterVideos = page.getByTestId('type-filter-video');
     this.searchInput = page.getByTestId('search-input');
+    this.selectModeButton = page.getByTestId('select-mode-button');
+    this.bulkTagBar = page.getByTestId('bulk-tag-bar');
+    this.bulkSelectedCount = page.getByTestId('bulk-selected-count');
+    this.bulkAddTagsInput = page.getByTestId('bulk-add-tags').getByTestId('tag-input-field');
+    this.bulkRemoveTagsInput = page.getByTestId('bulk-remove-tags').getByTestId('tag-input-field');
+    this.bulkApplyButton = page.getByTestId('bulk-apply-button');
   }
 
   async goto() {
@@ -64,6 +80,40 @@ export class BrowsePage {
     await this.addFilterTagInput.press('Escape');
   }
 
+  async openPopularTags() {
+    await this.addFilterTagButton.click();
+    await this.popularTagSuggestions.first().waitFor();
+  }
+
+  async filterByPopularTag(tag: string) {
+    await this.openPopularTags();
+    await this.popularTagSuggestions.filter({ hasText: tag }).click();
+    await this.addFilterTagInput.press('Escape');
+  }
+
+  cardSelect(index: number) {
+    return this.gridItems.nth(index).getByTestId('card-select');
+  }
+
+  async selectCards(indices: number[]) {
+    await this.selectModeButton.click();
+    for (const i of indices) {
+      await this.gridItems.nth(i).click();
+    }
+  }
+
+  async bulkEditTags({ add = [], remove = [] }: { add?: string[]; remove?: string[] }) {
+    for (const tag of add) {
+      await this.bulkAddTagsInput.fill(tag);
+      await this.bulkAddTagsInput.press('Enter');
+    }
+    for (const tag of remove) {
+      await this.bulkRemoveTagsInput.fill(tag);
+      await this.bulkRemoveTagsInput.press('Enter');
+    }
+    await this.bulkApplyButton.click();
+  }
+
   async removeFilterTag(tag: string) {
     await this.filterTagChips.filter({ hasText: tag }).getByRole('button').click();
   }
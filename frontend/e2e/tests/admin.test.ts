@@ -0,0 +1,54 @@
+import { e2eTest, expect } from '../fixtures.ts';
+
+e2eTest.beforeEach(async ({ page, registerPage }) => {
+  await registerPage.register('admin', 'correct-horse-battery');
+  await page.waitForURL('/');
+});
+
+e2eTest('admin can reprocess thumbnails for a media type', async ({ page, uploadPage, navBar, adminPage }) => {
+  await uploadPage.upload('sokerivarasto.jpg');
+  await page.waitForURL(/\/media\//);
+  await uploadPage.upload('markus.png');
+  await page.waitForURL(/\/media\//);
+
+  await navBar.goToAdmin('admin');
+  await expect(adminPage.heading).toBeVisible();
+
+  await adminPage.reprocessThumbnails('image');
+  await expect(adminPage.reprocessResult).toHaveText('2 of 2 reprocessed');
+
+  await adminPage.reprocessThumbnails('video');
+  await expect(adminPage.reprocessResult).toHaveText('0 of 0 reprocessed');
+});
+
+e2eTest('reprocess thumbnails runs in the background and reports progress', async ({ page, uploadPage }) => {
+  await uploadPage.upload('sokerivarasto.jpg');
+  await page.waitForURL(/\/media\//);
+
+  const res = await page.request.post('/api/admin/reprocess-thumbnails');
+  expect(res.status()).toBe(202);
+  expect((await res.json()).running).toBe(true);
+
+  await expect
+    .poll(async () => (await (await page.request.get('/api/admin/reprocess-thumbnails')).json()).running)
+    .toBe(false);
+  const body = await (await page.request.get('/api/admin/reprocess-thumbnails')).json();
+  expect(body.total).toBe(1);
+  expect(body.processed).toBe(1);
+  expect(body.succeeded).toBe(1);
+  expect(body.failed).toEqual([]);
+  expect(body.error).toBeNull();
+});
+
+e2eTest('non-admin cannot reprocess thumbnails', async ({ page, navBar, adminPage, registerPage }) => {
+  await navBar.goToAdmin('admin');
+  const inviteCode = await adminPage.createInvite();
+  await navBar.logout('admin');
+  await registerPage.register('member', 'correct-horse-battery', inviteCode);
+  await page.waitForURL('/');
+
+  const res = await page.request.post('/api/admin/reprocess-thumbnails');
+  expect(res.status()).toBe(403);
+  const status = await page.request.get('/api/admin/reprocess-thumbnails');
+  expect(status.status()).toBe(403);
+});
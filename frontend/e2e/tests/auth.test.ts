@@ -1,7 +1,7 @@
 import { e2eTest, expect } from '../fixtures.ts';
 
 e2eTest('register first user as admin and see home page', async ({ page, registerPage, navBar }) => {
-  await registerPage.register('testadmin', 'password123');
+  await registerPage.register('testadmin', 'correct-horse-battery');
 
   // Should redirect to home
   await expect(page).toHaveURL('/');
@@ -14,7 +14,7 @@ e2eTest('register first user as admin and see home page', async ({ page, registe
 
 e2eTest('login with existing user', async ({ page, registerPage, loginPage, navBar }) => {
   // First, register a user
-  await registerPage.register('loginuser', 'password123');
+  await registerPage.register('loginuser', 'correct-horse-battery');
   await expect(page).toHaveURL('/');
 
   // Log out via user menu
@@ -22,7 +22,7 @@ e2eTest('login with existing user', async ({ page, registerPage, loginPage, navB
   await expect(page).toHaveURL('/login');
 
   // Log back in
-  await loginPage.login('loginuser', 'password123');
+  await loginPage.login('loginuser', 'correct-horse-battery');
 
   await expect(page).toHaveURL('/');
   await expect(page.getByText('loginuser')).toBeVisible();
@@ -30,7 +30,7 @@ e2eTest('login with existing user', async ({ page, registerPage, loginPage, navB
 
 e2eTest('admin can create invite and second user registers with it', async ({ page, registerPage, navBar, adminPage }) => {
   // Register first user (admin)
-  await registerPage.register('admin', 'password123');
+  await registerPage.register('admin', 'correct-horse-battery');
   await expect(page).toHaveURL('/');
 
   // Go to admin page via user menu
@@ -44,7 +44,7 @@ e2eTest('admin can create invite and second user registers with it', async ({ pa
   await expect(page).toHaveURL('/login');
 
   // Register second user with invite code
-  await registerPage.register('member', 'password123', inviteCode);
+  await registerPage.register('member', 'correct-horse-battery', inviteCode);
 
   // Should land on home page
   await expect(page).toHaveURL('/');
@@ -57,15 +57,102 @@ e2eTest('admin can create invite and second user registers with it', async ({ pa
 
 e2eTest('register without invite code fails when users exist', async ({ page, registerPage, navBar }) => {
   // Register first user
-  await registerPage.register('firstuser', 'password123');
+  await registerPage.register('firstuser', 'correct-horse-battery');
   await expect(page).toHaveURL('/');
 
   // Log out via user menu
   await navBar.logout('firstuser');
 
   // Try to register without invite code
-  await registerPage.register('seconduser', 'password123');
+  await registerPage.register('seconduser', 'correct-horse-battery');
 
   // Should show error
   await expect(page.getByText('Invite code required')).toBeVisible();
 });
+
+e2eTest('multi-use invite can be redeemed until its limit', async ({ page, registerPage, navBar, adminPage }) => {
+  await registerPage.register('admin', 'correct-horse-battery');
+  await navBar.goToAdmin('admin');
+  const inviteCode = await adminPage.createInvite({ maxUses: 2 });
+  await expect(page.getByText('0 / 2')).toBeVisible();
+  await navBar.logout('admin');
+
+  await registerPage.register('first', 'correct-horse-battery', inviteCode);
+  await expect(page).toHaveURL('/');
+  await navBar.logout('first');
+
+  await registerPage.register('second', 'correct-horse-battery', inviteCode);
+  await expect(page).toHaveURL('/');
+  await navBar.logout('second');
+
+  // Third registration exceeds max_uses
+  await registerPage.register('third', 'correct-horse-battery', inviteCode);
+  await expect(page.getByText('Invalid or expired invite code')).toBeVisible();
+});
+
+e2eTest('admin-role invite registers an admin', async ({ page, registerPage, navBar, adminPage }) => {
+  await registerPage.register('admin', 'correct-horse-battery');
+  await navBar.goToAdmin('admin');
+  const inviteCode = await adminPage.createInvite({ role: 'admin' });
+  await navBar.logout('admin');
+
+  await registerPage.register('coadmin', 'correct-horse-battery', inviteCode);
+  await expect(page).toHaveURL('/');
+
+  await navBar.openMenu('coadmin');
+  await expect(navBar.adminItem).toBeVisible();
+});
+
+e2eTest('account is locked after repeated failed logins', async ({ page, registerPage, loginPage, navBar }) => {
+  await registerPage.register('lockme', 'correct-horse-battery');
+  await navBar.logout('lockme');
+  await expect(page).toHaveURL('/login');
+
+  for (let i = 0; i < 5; i++) {
+    await loginPage.login('lockme', 'wrong-password');
+    await expect(page.getByText('Invalid username or password')).toBeVisible();
+  }
+
+  // Even the correct password is rejected while locked
+  await loginPage.login('lockme', 'correct-horse-battery');
+  await expect(page.getByText(/Too many failed login attempts/)).toBeVisible();
+  await expect(page).toHaveURL('/login');
+
+  const res = await page.request.post('/api/auth/login', {
+    data: { username: 'lockme', password: 'correct-horse-battery' },
+  });
+  expect(res.status()).toBe(429);
+  expect(res.headers()['retry-after']).toBeTruthy();
+});
+
+e2eTest('successful login resets the failed attempt counter', async ({ page, registerPage, loginPage, navBar }) => {
+  await registerPage.register('resetme', 'correct-horse-battery');
+  await navBar.logout('resetme');
+
+  for (let i = 0; i < 4; i++) {
+    await loginPage.login('resetme', 'wrong-password');
+    await expect(page.getByText('Invalid username or password')).toBeVisible();
+  }
+  await loginPage.login('resetme', 'correct-horse-battery');
+  await expect(page).toHaveURL('/');
+  await navBar.logout('resetme');
+
+  // Counter starts over, so four more failures don't lock the account
+  for (let i = 0; i < 4; i++) {
+    await loginPage.login('resetme', 'wrong-password');
+    await expect(page.getByText('Invalid username or password')).toBeVisible();
+  }
+  await loginPage.login('resetme', 'correct-horse-battery');
+  await expect(page).toHaveURL('/');
+});
+
+e2eTest('registration rejects weak passwords', async ({ page, registerPage }) => {
+  await registerPage.register('weakling', 'password123');
+  await expect(page.getByText('Password is too common')).toBeVisible();
+
+  await registerPage.register('weakling', 'xweaklingx99');
+  await expect(page.getByText('Password must not contain your username')).toBeVisible();
+
+  await registerPage.register('weakling', 'correct-horse-battery');
+  await expect(page).toHaveURL('/');
+});
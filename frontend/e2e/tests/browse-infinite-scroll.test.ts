@@ -5,7 +5,7 @@ const SEED_COUNT = 25;
 const PAGE_SIZE = 20;
 
 e2eTest.beforeEach(async ({ page, registerPage }) => {
-  await registerPage.register('scroller', 'password123');
+  await registerPage.register('scroller', 'correct-horse-battery');
   await page.waitForURL('/');
 });
 
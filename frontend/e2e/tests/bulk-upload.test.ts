@@ -1,7 +1,7 @@
 import { e2eTest, expect } from '../fixtures.ts';
 
 e2eTest.beforeEach(async ({ page, registerPage }) => {
-  await registerPage.register('bulkuploader', 'password123');
+  await registerPage.register('bulkuploader', 'correct-horse-battery');
   await page.waitForURL('/');
 });
 
@@ -13,7 +13,7 @@ const TEST_DATA_DIR = path.resolve(
 
 e2eTest.describe('drag-drop and paste upload', () => {
   e2eTest.beforeEach(async ({ page, registerPage }) => {
-    await registerPage.register('dropper', 'password123');
+    await registerPage.register('dropper', 'correct-horse-battery');
     await page.waitForURL('/');
   });
 
@@ -4,7 +4,7 @@ import { e2eTest, expect } from '../fixtures.ts';
 const TEST_DATA_DIR = path.resolve(import.meta.dirname, '..', '..', '..', 'test_data', 'memes');
 
 e2eTest.beforeEach(async ({ page, registerPage }) => {
-  await registerPage.register('editor', 'password123');
+  await registerPage.register('editor', 'correct-horse-battery');
   await page.waitForURL('/');
 });
 
@@ -36,6 +36,22 @@ e2eTest('editing title preserves description and vice versa', async ({ page, upl
   await expect(mediaPage.title).toHaveText('New Title');
 });
 
+e2eTest('manually edited OCR text has no confidence', async ({ page, uploadPage }) => {
+  await uploadPage.upload('sokerivarasto.jpg');
+  await page.waitForURL(/\/media\//);
+  const id = page.url().split('/media/')[1];
+
+  const res = await page.request.patch(`/api/media/${id}`, { data: { ocr_text: 'typed by hand' } });
+  expect(res.ok()).toBe(true);
+  const data = await res.json();
+  expect(data.ocr_text).toBe('typed by hand');
+  expect(data.ocr_confidence).toBeNull();
+
+  await page.reload();
+  await expect(page.getByTestId('ocr-text')).toHaveValue('typed by hand');
+  await expect(page.getByTestId('ocr-low-confidence')).toHaveCount(0);
+});
+
 e2eTest('replace file changes the image', async ({ page, uploadPage, mediaPage }) => {
   await uploadPage.upload('sokerivarasto.jpg');
   await page.waitForURL(/\/media\//);
@@ -2,7 +2,7 @@ import { e2eTest, expect } from '../fixtures.ts';
 
 e2eTest.describe('media overlay', () => {
   e2eTest.beforeEach(async ({ page, registerPage }) => {
-    await registerPage.register('overlay-user', 'password123');
+    await registerPage.register('overlay-user', 'correct-horse-battery');
     await page.waitForURL('/');
   });
 
@@ -1,7 +1,7 @@
 import { e2eTest, expect } from '../fixtures.ts';
 
 e2eTest.beforeEach(async ({ registerPage, page }) => {
-  await registerPage.register('searcher', 'password123');
+  await registerPage.register('searcher', 'correct-horse-battery');
   await page.waitForURL('/');
 });
 
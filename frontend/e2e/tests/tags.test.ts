@@ -1,8 +1,10 @@
+import type { Page } from '@playwright/test';
 import { e2eTest, expect } from '../fixtures.ts';
+import type { UploadPage } from '../pom/UploadPage.ts';
 
 e2eTest.describe('tagging', () => {
   e2eTest.beforeEach(async ({ page, registerPage }) => {
-    await registerPage.register('tagger', 'password123');
+    await registerPage.register('tagger', 'correct-horse-battery');
     await page.waitForURL('/');
   });
 
@@ -62,9 +64,103 @@ e2eTest.describe('tagging', () => {
   });
 });
 
+e2eTest.describe('bulk tagging', () => {
+  e2eTest.beforeEach(async ({ page, registerPage }) => {
+    await registerPage.register('bulktagger', 'correct-horse-battery');
+    await page.waitForURL('/');
+  });
+
+  async function uploadAndGetId(page: Page, uploadPage: UploadPage, file: string) {
+    await uploadPage.upload(file);
+    await page.waitForURL(/\/media\//);
+    return page.url().split('/media/')[1];
+  }
+
+  e2eTest('adds and removes tags across media', async ({ page, uploadPage, mediaPage }) => {
+    const first = await uploadAndGetId(page, uploadPage, 'sokerivarasto.jpg');
+    await mediaPage.editTags({ add: ['old'] });
+    await expect(mediaPage.saveTagsButton).not.toBeVisible();
+    const second = await uploadAndGetId(page, uploadPage, 'markus.png');
+
+    const res = await page.request.post('/api/media/tags/bulk', {
+      data: { media_ids: [first, second], add: ['Shared', 'meme'], remove: ['old'] },
+    });
+    expect(res.ok()).toBe(true);
+    const { results } = await res.json();
+
+    expect(results).toEqual([
+      { media_id: first, ok: true, tags: ['meme', 'shared'] },
+      { media_id: second, ok: true, tags: ['meme', 'shared'] },
+    ]);
+
+    await page.goto(`/media/${first}`);
+    await expect(mediaPage.tagChips).toHaveCount(2);
+  });
+
+  e2eTest('reports missing media per item', async ({ page, uploadPage }) => {
+    const id = await uploadAndGetId(page, uploadPage, 'sokerivarasto.jpg');
+    const missing = '00000000-0000-0000-0000-000000000000';
+
+    const res = await page.request.post('/api/media/tags/bulk', {
+      data: { media_ids: [id, missing], add: ['bulk'] },
+    });
+    expect(res.ok()).toBe(true);
+    const { results } = await res.json();
+
+    expect(results[0]).toEqual({ media_id: id, ok: true, tags: ['bulk'] });
+    expect(results[1]).toMatchObject({ media_id: missing, ok: false, tags: [] });
+  });
+
+  e2eTest('rejects invalid tags without applying anything', async ({ page, uploadPage }) => {
+    const id = await uploadAndGetId(page, uploadPage, 'sokerivarasto.jpg');
+
+    const res = await page.request.post('/api/media/tags/bulk', {
+      data: { media_ids: [id], add: ['fine', 'not fine'] },
+    });
+    expect(res.status()).toBe(400);
+
+    const media = await (await page.request.get(`/api/media/${id}`)).json();
+    expect(media.tags).toEqual([]);
+  });
+
+  e2eTest('tags selected media from the browse grid', async ({
+    page,
+    uploadPage,
+    mediaPage,
+    browsePage,
+  }) => {
+    await uploadAndGetId(page, uploadPage, 'sokerivarasto.jpg');
+    await mediaPage.editTags({ add: ['old'] });
+    await expect(mediaPage.saveTagsButton).not.toBeVisible();
+    await uploadAndGetId(page, uploadPage, 'markus.png');
+    await uploadAndGetId(page, uploadPage, 'questionable_ethics.gif');
+
+    await browsePage.goto();
+    await expect(browsePage.gridItems).toHaveCount(3);
+
+    await browsePage.selectCards([1, 2]);
+    await expect(browsePage.bulkSelectedCount).toHaveText('2 selected');
+    await expect(browsePage.cardSelect(0)).toHaveAttribute('aria-checked', 'false');
+    await expect(browsePage.cardSelect(1)).toHaveAttribute('aria-checked', 'true');
+    await expect(page).toHaveURL('/');
+
+    await browsePage.bulkEditTags({ add: ['shared'], remove: ['old'] });
+    await expect(browsePage.bulkSelectedCount).toHaveText('0 selected');
+
+    const cardTags = page.getByTestId('card-tag');
+    await expect(cardTags.filter({ hasText: 'shared' })).toHaveCount(2);
+    await expect(cardTags.filter({ hasText: 'old' })).toHaveCount(0);
+
+    await browsePage.selectModeButton.click();
+    await expect(browsePage.bulkTagBar).not.toBeVisible();
+    await browsePage.filterByTag('shared');
+    await expect(browsePage.gridItems).toHaveCount(2);
+  });
+});
+
 e2eTest.describe('tag filtering', () => {
   e2eTest.beforeEach(async ({ page, registerPage, uploadPage, mediaPage }) => {
-    await registerPage.register('filterer', 'password123');
+    await registerPage.register('filterer', 'correct-horse-battery');
     await page.waitForURL('/');
 
     // Upload media and add tags on detail page
@@ -134,4 +230,42 @@ e2eTest.describe('tag filtering', () => {
     await expect(page).toHaveURL(/tags=cats/);
   });
 
+  e2eTest('popular tags are ordered by usage', async ({ page }) => {
+    const res = await page.request.get('/api/tags/popular');
+    expect(res.ok()).toBe(true);
+    const data = await res.json();
+
+    expect(data.tags.map((t: { name: string }) => t.name)).toEqual(['funny', 'cats', 'dogs']);
+    expect(data.tags[0].media_count).toBe(2);
+    expect(data.next_offset).toBeNull();
+  });
+
+  e2eTest('popular tags are paginated', async ({ page }) => {
+    const first = await (await page.request.get('/api/tags/popular?limit=2')).json();
+    expect(first.tags).toHaveLength(2);
+    expect(first.next_offset).toBe(2);
+
+    const second = await (
+      await page.request.get(`/api/tags/popular?limit=2&offset=${first.next_offset}`)
+    ).json();
+    expect(second.tags.map((t: { name: string }) => t.name)).toEqual(['dogs']);
+    expect(second.next_offset).toBeNull();
+  });
+
+  e2eTest('filter input suggests popular tags', async ({ browsePage }) => {
+    await browsePage.goto();
+
+    await browsePage.openPopularTags();
+    await expect(browsePage.popularTagSuggestions).toHaveText([/^funny/, /^cats/, /^dogs/]);
+    await expect(browsePage.popularTagCounts).toHaveText(['2', '1', '1']);
+    await browsePage.addFilterTagInput.press('Escape');
+
+    await browsePage.filterByPopularTag('funny');
+    await expect(browsePage.filterTagChips).toHaveCount(1);
+    await expect(browsePage.filterTagChips.nth(0)).toContainText('funny');
+    await expect(browsePage.gridItems).toHaveCount(2);
+
+    await browsePage.openPopularTags();
+    await expect(browsePage.popularTagSuggestions).toHaveText([/^cats/, /^dogs/]);
+  });
 });
@@ -1,7 +1,7 @@
 import { e2eTest, expect } from '../fixtures.ts';
 
 e2eTest.beforeEach(async ({ registerPage, page }) => {
-  await registerPage.register('filterer', 'password123');
+  await registerPage.register('filterer', 'correct-horse-battery');
   await page.waitForURL('/');
 });
 
@@ -4,7 +4,7 @@ import path from 'node:path';
 const TEST_DATA_DIR = path.resolve(import.meta.dirname, '..', '..', '..', 'test_data', 'memes');
 
 e2eTest.beforeEach(async ({ page, registerPage }) => {
-  await registerPage.register('uploader', 'password123');
+  await registerPage.register('uploader', 'correct-horse-battery');
   await page.waitForURL('/');
 });
 
@@ -147,3 +147,41 @@ e2eTest('video thumbnail file is servable after upload', async ({ page, uploadPa
   const thumbRes = await page.request.get(data.thumbnail_url);
   expect(thumbRes.ok()).toBe(true);
 });
+
+e2eTest('original image is negotiated to WebP only when accepted', async ({ page, uploadPage }) => {
+  await uploadPage.goto();
+  // Stored without compression, so the WebP variant is always the smaller one
+  await uploadPage.fileInput.setInputFiles(path.join(TEST_DATA_DIR, 'uncompressed.png'));
+
+  const responsePromise = page.waitForResponse(
+    (res) => res.url().includes('/api/media/upload') && res.status() === 200,
+  );
+  await uploadPage.submitButton.click();
+  const data = await (await responsePromise).json();
+
+  const pngRes = await page.request.get(data.file_url, { headers: { Accept: 'image/png,*/*' } });
+  expect(pngRes.ok()).toBe(true);
+  expect(pngRes.headers()['content-type']).toBe('image/png');
+  expect(pngRes.headers()['vary']).toContain('accept');
+  const originalSize = (await pngRes.body()).length;
+
+  const webpRes = await page.request.get(data.file_url, {
+    headers: { Accept: 'image/avif,image/webp,*/*' },
+  });
+  expect(webpRes.ok()).toBe(true);
+  expect(webpRes.headers()['vary']).toContain('accept');
+  expect(webpRes.headers()['content-type']).toBe('image/webp');
+  expect((await webpRes.body()).length).toBeLessThan(originalSize);
+
+  // AVIF is not negotiated, so an AVIF-only client gets the original
+  const avifRes = await page.request.get(data.file_url, { headers: { Accept: 'image/avif,*/*' } });
+  expect(avifRes.ok()).toBe(true);
+  expect(avifRes.headers()['content-type']).toBe('image/png');
+  expect((await avifRes.body()).length).toBe(originalSize);
+
+  // Clipboard copies always stay PNG
+  const clipboardRes = await page.request.get(data.clipboard_url, {
+    headers: { Accept: 'image/webp,*/*' },
+  });
+  expect(clipboardRes.headers()['content-type']).toBe('image/png');
+});
@@ -0,0 +1,21 @@
+import { apiFetch } from './client';
+import type { MediaTypeFilter } from './media';
+
+export interface ReprocessStatus {
+  running: boolean;
+  media_type: MediaTypeFilter | null;
+  total: number;
+  processed: number;
+  succeeded: number;
+  failed: { media_id: string; error: string }[];
+  error: string | null;
+}
+
+export function reprocessThumbnails(mediaType?: MediaTypeFilter) {
+  const qs = mediaType ? `?media_type=${mediaType}` : '';
+  return apiFetch<ReprocessStatus>(`/admin/reprocess-thumbnails${qs}`, { method: 'POST' });
+}
+
+export function getReprocessStatus() {
+  return apiFetch<ReprocessStatus | null>('/admin/reprocess-thumbnails');
+}
@@ -1,21 +1,31 @@
 import { apiFetch } from './client';
+import type { User } from './auth';
 
 export interface Invite {
   id: string;
   code: string;
   created_by: string;
   used_by: string | null;
+  max_uses: number;
+  used_count: number;
+  role: User['role'];
   expires_at: string;
   created_at: string;
 }
 
+export interface CreateInviteOptions {
+  expires_in_hours?: number;
+  max_uses?: number;
+  role?: User['role'];
+}
+
 export function listInvites() {
   return apiFetch<Invite[]>('/invites');
 }
 
-export function createInvite(expires_in_hours?: number) {
+export function createInvite(options: CreateInviteOptions = {}) {
   return apiFetch<Invite>('/invites', {
     method: 'POST',
-    body: JSON.stringify({ expires_in_hours }),
+    body: JSON.stringify(options),
   });
 }
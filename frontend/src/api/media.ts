@@ -13,6 +13,7 @@ export interface MediaItem {
   width: number | null;
   height: number | null;
   ocr_text: string | null;
+  ocr_confidence: number | null;
   uploaded_by: string;
   created_at: string;
   tags: string[];
@@ -29,6 +30,15 @@ export interface Tag {
   name: string;
 }
 
+export interface TagWithCount extends Tag {
+  media_count: number;
+}
+
+export interface PopularTagsPage {
+  tags: TagWithCount[];
+  next_offset: number | null;
+}
+
 export function uploadMedia(file: File, name?: string, description?: string, tags?: string[]) {
   const form = new FormData();
   form.append('file', file);
@@ -84,6 +94,20 @@ export function replaceMediaFile(id: string, file: File) {
   return apiFetchFormData<MediaItem>(`/media/${id}/file`, form, 'PUT');
 }
 
+export interface BulkTagResult {
+  media_id: string;
+  ok: boolean;
+  error?: string;
+  tags: string[];
+}
+
+export function bulkUpdateTags(mediaIds: string[], add: string[], remove: string[] = []) {
+  return apiFetch<{ results: BulkTagResult[] }>('/media/tags/bulk', {
+    method: 'POST',
+    body: JSON.stringify({ media_ids: mediaIds, add, remove }),
+  });
+}
+
 export function regenerateThumbnail(id: string) {
   return apiFetch<MediaItem>(`/media/${id}/regenerate-thumbnail`, { method: 'POST' });
 }
@@ -102,3 +126,11 @@ export function searchTags(q: string) {
   const qs = params.toString();
   return apiFetch<{ tags: Tag[] }>(`/tags${qs ? `?${qs}` : ''}`);
 }
+
+export function getPopularTags(limit?: number, offset?: number) {
+  const params = new URLSearchParams();
+  if (limit) params.set('limit', String(limit));
+  if (offset) params.set('offset', String(offset));
+  const qs = params.toString();
+  return apiFetch<PopularTagsPage>(`/tags/popular${qs ? `?${qs}` : ''}`);
+}
@@ -0,0 +1,93 @@
+import { useState } from 'react';
+import { useMutation, useQueryClient } from '@tanstack/react-query';
+import styled from 'styled-components';
+import { bulkUpdateTags } from '../api/media';
+import { Button } from './Button';
+import { TagInput } from './TagInput';
+import { useToast } from './Toast';
+
+interface BulkTagBarProps {
+  selectedIds: string[];
+  onDone: () => void;
+  onCancel: () => void;
+}
+
+const Bar = styled.div`
+  position: sticky;
+  top: 0;
+  z-index: 5;
+  display: flex;
+  flex-wrap: wrap;
+  gap: ${({ theme }) => theme.spacing.sm};
+  align-items: center;
+  background: ${({ theme }) => theme.colors.surface};
+  border: 1px solid ${({ theme }) => theme.colors.border};
+  border-radius: ${({ theme }) => theme.borderRadius.md};
+  padding: ${({ theme }) => theme.spacing.sm} ${({ theme }) => theme.spacing.md};
+  margin-bottom: ${({ theme }) => theme.spacing.lg};
+`;
+
+const Count = styled.span`
+  font-size: ${({ theme }) => theme.fontSize.sm};
+  color: ${({ theme }) => theme.colors.textSecondary};
+  white-space: nowrap;
+`;
+
+const Field = styled.div`
+  flex: 1;
+  min-width: 200px;
+`;
+
+export function BulkTagBar({ selectedIds, onDone, onCancel }: BulkTagBarProps) {
+  const queryClient = useQueryClient();
+  const { toast } = useToast();
+  const [add, setAdd] = useState<string[]>([]);
+  const [remove, setRemove] = useState<string[]>([]);
+
+  const mutation = useMutation({
+    mutationFn: () => bulkUpdateTags(selectedIds, add, remove),
+    onSuccess: ({ results }) => {
+      const failed = results.filter((r) => !r.ok).length;
+      if (failed > 0) {
+        toast(`Failed to update ${failed} of ${results.length} items`, 'error');
+      } else {
+        toast(`Updated tags on ${results.length} items`);
+      }
+      setAdd([]);
+      setRemove([]);
+      onDone();
+    },
+    onError: () => {
+      toast('Failed to update tags', 'error');
+    },
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: ['media-list'] });
+      queryClient.invalidateQueries({ queryKey: ['popular-tags'] });
+    },
+  });
+
+  const canApply = selectedIds.length > 0 && (add.length > 0 || remove.length > 0);
+
+  return (
+    <Bar data-testid="bulk-tag-bar">
+      <Count data-testid="bulk-selected-count">{selectedIds.length} selected</Count>
+      <Field data-testid="bulk-add-tags">
+        <TagInput tags={add} onChange={setAdd} placeholder="Tags to add..." />
+      </Field>
+      <Field data-testid="bulk-remove-tags">
+        <TagInput tags={remove} onChange={setRemove} placeholder="Tags to remove..." />
+      </Field>
+      <Button
+        onClick={() => mutation.mutate()}
+        disabled={!canApply}
+        loading={mutation.isPending}
+        data-testid="bulk-apply-button"
+      >
+        Apply
+      </Button>
+      <Button variant="ghost" onClick={onCancel} data-testid="bulk-cancel-button">
+        Cancel
+      </Button>
+    </Bar>
+  );
+}
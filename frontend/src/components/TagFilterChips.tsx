@@ -2,7 +2,7 @@ import { useEffect, useRef, useState } from 'react';
 import { Cross1Icon, PlusIcon } from '@radix-ui/react-icons';
 import { useQuery } from '@tanstack/react-query';
 import styled from 'styled-components';
-import { searchTags } from '../api/media';
+import { getPopularTags, searchTags } from '../api/media';
 
 interface TagFilterChipsProps {
   tags: string[];
@@ -126,8 +126,15 @@ const DropdownItem = styled.li<{ $highlighted?: boolean }>`
   }
 `;
 
+const TagCount = styled.span`
+  margin-left: ${({ theme }) => theme.spacing.sm};
+  color: ${({ theme }) => theme.colors.textSecondary};
+`;
+
 // --- Helpers ---
 
+const POPULAR_TAG_LIMIT = 10;
+
 function normalizeTag(input: string): string {
   return input.trim().toLowerCase().replace(/\s/g, '').slice(0, 30);
 }
@@ -155,13 +162,23 @@ export function TagFilterChips({ tags, onAdd, onRemove }: TagFilterChipsProps) {
     enabled: showInput && debouncedQuery.length > 0,
   });
 
+  // Popular tags are suggested while the input is still empty
+  const { data: popular } = useQuery({
+    queryKey: ['popular-tags', POPULAR_TAG_LIMIT],
+    queryFn: () => getPopularTags(POPULAR_TAG_LIMIT),
+    enabled: showInput,
+  });
+
+  const showingPopular = input.length === 0;
+
   // Filter out tags already in the tags prop
   const tagsSet = new Set(tags);
-  const filteredSuggestions =
-    suggestions?.tags.filter((t) => !tagsSet.has(t.name)) ?? [];
+  const filteredSuggestions: { id: string; name: string; media_count?: number }[] =
+    ((showingPopular ? popular?.tags : suggestions?.tags) ?? []).filter(
+      (t) => !tagsSet.has(t.name),
+    );
 
-  const showDropdown =
-    showInput && filteredSuggestions.length > 0 && input.length > 0;
+  const showDropdown = showInput && filteredSuggestions.length > 0;
 
   // --- Actions ---
 
@@ -277,7 +294,10 @@ export function TagFilterChips({ tags, onAdd, onRemove }: TagFilterChipsProps) {
             autoComplete="off"
           />
           {showDropdown && (
-            <Dropdown role="listbox" data-testid="tag-suggestions">
+            <Dropdown
+              role="listbox"
+              data-testid={showingPopular ? 'popular-tag-suggestions' : 'tag-suggestions'}
+            >
               {filteredSuggestions.map((tag, i) => (
                 <DropdownItem
                   key={tag.id}
@@ -289,6 +309,9 @@ export function TagFilterChips({ tags, onAdd, onRemove }: TagFilterChipsProps) {
                   }}
                 >
                   {tag.name}
+                  {tag.media_count !== undefined && (
+                    <TagCount data-testid="popular-tag-count">{tag.media_count}</TagCount>
+                  )}
                 </DropdownItem>
               ))}
             </Dropdown>
@@ -1,7 +1,10 @@
 import { useState } from 'react';
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import styled from 'styled-components';
+import { getReprocessStatus, reprocessThumbnails } from '../api/admin';
+import type { User } from '../api/auth';
 import { listInvites, createInvite, type Invite } from '../api/invites';
+import type { MediaTypeFilter } from '../api/media';
 import { Button, Input } from '../components';
 
 const Container = styled.div`
@@ -76,13 +79,34 @@ const Code = styled.code`
   font-size: ${({ theme }) => theme.fontSize.sm};
 `;
 
+const Select = styled.select`
+  background: ${({ theme }) => theme.colors.bg};
+  border: 1px solid ${({ theme }) => theme.colors.border};
+  border-radius: ${({ theme }) => theme.borderRadius.md};
+  color: ${({ theme }) => theme.colors.text};
+  font-size: ${({ theme }) => theme.fontSize.md};
+  padding: ${({ theme }) => theme.spacing.sm} ${({ theme }) => theme.spacing.md};
+`;
+
+const ResultText = styled.p`
+  font-size: ${({ theme }) => theme.fontSize.sm};
+  color: ${({ theme }) => theme.colors.textSecondary};
+`;
+
+const Progress = styled.progress`
+  width: 100%;
+  max-width: 400px;
+  height: 8px;
+  accent-color: ${({ theme }) => theme.colors.primary};
+`;
+
 const HoursLabel = styled.span`
   color: ${({ theme }) => theme.colors.textSecondary};
   font-size: ${({ theme }) => theme.fontSize.sm};
 `;
 
 function inviteStatus(invite: Invite): 'active' | 'used' | 'expired' {
-  if (invite.used_by) return 'used';
+  if (invite.used_count >= invite.max_uses) return 'used';
   if (new Date(invite.expires_at) < new Date()) return 'expired';
   return 'active';
 }
@@ -90,6 +114,8 @@ function inviteStatus(invite: Invite): 'active' | 'used' | 'expired' {
 export function AdminPage() {
   const queryClient = useQueryClient();
   const [hours, setHours] = useState('72');
+  const [maxUses, setMaxUses] = useState('1');
+  const [inviteRole, setInviteRole] = useState<User['role']>('member');
 
   const { data: invites = [], isLoading } = useQuery({
     queryKey: ['invites'],
@@ -97,10 +123,27 @@ export function AdminPage() {
   });
 
   const mutation = useMutation({
-    mutationFn: () => createInvite(parseInt(hours) || 72),
+    mutationFn: () =>
+      createInvite({
+        expires_in_hours: parseInt(hours) || 72,
+        max_uses: parseInt(maxUses) || 1,
+        role: inviteRole,
+      }),
     onSuccess: () => queryClient.invalidateQueries({ queryKey: ['invites'] }),
   });
 
+  const [reprocessType, setReprocessType] = useState<MediaTypeFilter | ''>('');
+  const { data: reprocessStatus } = useQuery({
+    queryKey: ['reprocess-status'],
+    queryFn: getReprocessStatus,
+    refetchInterval: (query) => (query.state.data?.running ? 500 : false),
+  });
+  const reprocess = useMutation({
+    mutationFn: () => reprocessThumbnails(reprocessType || undefined),
+    onSuccess: (status) => queryClient.setQueryData(['reprocess-status'], status),
+  });
+  const reprocessRunning = reprocessStatus?.running ?? false;
+
   return (
     <Container>
       <Heading>Admin</Heading>
@@ -118,7 +161,26 @@ export function AdminPage() {
             style={{ width: 120 }}
           />
           <HoursLabel>hours</HoursLabel>
+          <Input
+            type="number"
+            value={maxUses}
+            onChange={(e) => setMaxUses(e.target.value)}
+            min="1"
+            max="100"
+            style={{ width: 80 }}
+            aria-label="Max uses"
+          />
+          <HoursLabel>uses</HoursLabel>
+          <Select
+            value={inviteRole}
+            onChange={(e) => setInviteRole(e.target.value as User['role'])}
+            aria-label="Invite role"
+          >
+            <option value="member">Member</option>
+            <option value="admin">Admin</option>
+          </Select>
         </CreateRow>
+        {mutation.error && <ResultText>{mutation.error.message}</ResultText>}
 
         {isLoading ? (
           <p>Loading...</p>
@@ -128,6 +190,8 @@ export function AdminPage() {
               <tr>
                 <Th>Code</Th>
                 <Th>Status</Th>
+                <Th>Uses</Th>
+                <Th>Role</Th>
                 <Th>Expires</Th>
                 <Th>Created</Th>
               </tr>
@@ -139,6 +203,8 @@ export function AdminPage() {
                   <tr key={invite.id}>
                     <Td><Code>{invite.code}</Code></Td>
                     <Td><Badge $variant={status}>{status}</Badge></Td>
+                    <Td>{invite.used_count} / {invite.max_uses}</Td>
+                    <Td>{invite.role}</Td>
                     <Td>{new Date(invite.expires_at).toLocaleDateString()}</Td>
                     <Td>{new Date(invite.created_at).toLocaleDateString()}</Td>
                   </tr>
@@ -146,7 +212,7 @@ export function AdminPage() {
               })}
               {invites.length === 0 && (
                 <tr>
-                  <Td colSpan={4} style={{ textAlign: 'center', color: '#888' }}>
+                  <Td colSpan={6} style={{ textAlign: 'center', color: '#888' }}>
                     No invites yet
                   </Td>
                 </tr>
@@ -155,6 +221,48 @@ export function AdminPage() {
           </Table>
         )}
       </Section>
+      <Section>
+        <SectionTitle>Thumbnails</SectionTitle>
+        <CreateRow>
+          <Select
+            value={reprocessType}
+            onChange={(e) => setReprocessType(e.target.value as MediaTypeFilter | '')}
+            aria-label="Media type"
+          >
+            <option value="">All media</option>
+            <option value="image">Images</option>
+            <option value="gif">GIFs</option>
+            <option value="video">Videos</option>
+          </Select>
+          <Button
+            onClick={() => reprocess.mutate()}
+            loading={reprocess.isPending || reprocessRunning}
+            data-testid="reprocess-button"
+          >
+            {reprocess.isPending || reprocessRunning ? 'Reprocessing...' : 'Reprocess thumbnails'}
+          </Button>
+        </CreateRow>
+        {reprocessStatus?.running && (
+          <>
+            <Progress
+              value={reprocessStatus.processed}
+              max={reprocessStatus.total || 1}
+              data-testid="reprocess-progress"
+            />
+            <ResultText>
+              {reprocessStatus.processed} of {reprocessStatus.total} processed
+            </ResultText>
+          </>
+        )}
+        {reprocessStatus && !reprocessStatus.running && (
+          <ResultText data-testid="reprocess-result">
+            {reprocessStatus.succeeded} of {reprocessStatus.total} reprocessed
+            {reprocessStatus.failed.length > 0 && `, ${reprocessStatus.failed.length} failed`}
+            {reprocessStatus.error && ` (stopped: ${reprocessStatus.error})`}
+          </ResultText>
+        )}
+        {reprocess.error && <ResultText>{reprocess.error.message}</ResultText>}
+      </Section>
     </Container>
   );
 }
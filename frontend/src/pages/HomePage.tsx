@@ -3,9 +3,9 @@ import { Link, useSearchParams } from 'react-router-dom';
 import { debounce } from 'es-toolkit';
 import { useInfiniteQuery } from '@tanstack/react-query';
 import styled from 'styled-components';
-import { MagnifyingGlassIcon } from '@radix-ui/react-icons';
+import { CheckIcon, MagnifyingGlassIcon } from '@radix-ui/react-icons';
 import { listMedia, type MediaItem, type MediaTypeFilter } from '../api/media';
-import { MasonryGrid, Media, MediaOverlay, TagFilterChips } from '../components';
+import { BulkTagBar, MasonryGrid, Media, MediaOverlay, TagFilterChips } from '../components';
 
 const Container = styled.div`
   padding: ${({ theme }) => theme.spacing.lg};
@@ -86,10 +86,12 @@ const TypeFilterButton = styled.button<{ $active?: boolean }>`
   }
 `;
 
-const Card = styled.div`
+const Card = styled.div<{ $selected?: boolean }>`
   border-radius: ${({ theme }) => theme.borderRadius.md};
   overflow: hidden;
   background: ${({ theme }) => theme.colors.surface};
+  outline: 2px solid ${({ theme, $selected }) => $selected ? theme.colors.primary : 'transparent'};
+  outline-offset: -2px;
 `;
 
 const CardLink = styled(Link)`
@@ -176,6 +178,27 @@ const PlayIcon = styled.div`
   }
 `;
 
+const SelectMark = styled.div<{ $checked?: boolean }>`
+  position: absolute;
+  bottom: ${({ theme }) => theme.spacing.sm};
+  left: ${({ theme }) => theme.spacing.sm};
+  width: 22px;
+  height: 22px;
+  border-radius: ${({ theme }) => theme.borderRadius.sm};
+  border: 2px solid #fff;
+  background: ${({ theme, $checked }) => $checked ? theme.colors.primary : 'rgba(0, 0, 0, 0.4)'};
+  color: #fff;
+  display: flex;
+  align-items: center;
+  justify-content: center;
+  pointer-events: none;
+
+  svg {
+    width: 16px;
+    height: 16px;
+  }
+`;
+
 const Sentinel = styled.div`
   height: 1px;
 `;
@@ -287,6 +310,26 @@ export function HomePage() {
     return () => debouncedSetSearch.cancel();
   }, [searchInput, debouncedSetSearch]);
 
+  const [selectMode, setSelectMode] = useState(false);
+  const [selected, setSelected] = useState<Set<string>>(new Set());
+
+  function toggleSelected(id: string) {
+    setSelected((prev) => {
+      const next = new Set(prev);
+      if (next.has(id)) {
+        next.delete(id);
+      } else {
+        next.add(id);
+      }
+      return next;
+    });
+  }
+
+  function exitSelectMode() {
+    setSelectMode(false);
+    setSelected(new Set());
+  }
+
   function tagFilterUrl(tag: string): string {
     const tags = filterTags.includes(tag) ? filterTags : [...filterTags, tag];
     const params = new URLSearchParams();
@@ -378,8 +421,22 @@ export function HomePage() {
               </TypeFilterButton>
             ))}
           </TypeFilterGroup>
+          <TypeFilterButton
+            $active={selectMode}
+            onClick={() => (selectMode ? exitSelectMode() : setSelectMode(true))}
+            data-testid="select-mode-button"
+          >
+            Select
+          </TypeFilterButton>
         </FilterRow>
       </FilterContainer>
+      {selectMode && (
+        <BulkTagBar
+          selectedIds={[...selected]}
+          onDone={() => setSelected(new Set())}
+          onCancel={exitSelectMode}
+        />
+      )}
       {isLoading && (
         <LoadingText>Loading...</LoadingText>
       )}
@@ -393,8 +450,15 @@ export function HomePage() {
         getItemHeight={getItemHeight}
         getItemKey={getItemKey}
         renderItem={(item) => (
-          <Card>
-            <CardLink to={`/media/${item.id}`}>
+          <Card $selected={selected.has(item.id)}>
+            <CardLink
+              to={`/media/${item.id}`}
+              onClick={(e) => {
+                if (!selectMode) return;
+                e.preventDefault();
+                toggleSelected(item.id);
+              }}
+            >
               <CardMedia $ratio={aspectRatio(item)}>
                 <Media
                   item={item.thumbnail_url ? { ...item, file_url: item.thumbnail_url, media_type: 'image' as const } : item}
@@ -403,13 +467,25 @@ export function HomePage() {
                 />
               </CardMedia>
               {item.media_type === 'video' && <PlayIcon />}
-              <MediaOverlay
-                fileUrl={item.file_url}
-                fileName={item.name ?? `media-${item.id}`}
-                mediaType={item.media_type}
-                clipboardUrl={item.clipboard_url}
-              />
+              {!selectMode && (
+                <MediaOverlay
+                  fileUrl={item.file_url}
+                  fileName={item.name ?? `media-${item.id}`}
+                  mediaType={item.media_type}
+                  clipboardUrl={item.clipboard_url}
+                />
+              )}
               {item.name && <NameOverlay data-overlay data-testid="card-name">{item.name}</NameOverlay>}
+              {selectMode && (
+                <SelectMark
+                  $checked={selected.has(item.id)}
+                  role="checkbox"
+                  aria-checked={selected.has(item.id)}
+                  data-testid="card-select"
+                >
+                  {selected.has(item.id) && <CheckIcon />}
+                </SelectMark>
+              )}
             </CardLink>
             {item.tags.length > 0 && (
               <CardTags>
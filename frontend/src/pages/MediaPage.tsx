@@ -133,6 +133,14 @@ const SectionLabel = styled.label`
   margin-bottom: ${({ theme }) => theme.spacing.xs};
 `;
 
+const LowConfidence = styled.span`
+  color: ${({ theme }) => theme.colors.error};
+  margin-left: ${({ theme }) => theme.spacing.sm};
+`;
+
+/** OCR results below this mean confidence are flagged for review. */
+const LOW_OCR_CONFIDENCE = 0.7;
+
 const Meta = styled.p`
   color: ${({ theme }) => theme.colors.textSecondary};
   font-size: ${({ theme }) => theme.fontSize.sm};
@@ -380,7 +388,14 @@ export function MediaPage() {
         data-testid={editingDescription ? 'edit-description' : 'description'}
       />
 
-      <SectionLabel>Recognized text</SectionLabel>
+      <SectionLabel>
+        Recognized text
+        {media.ocr_confidence !== null && media.ocr_confidence < LOW_OCR_CONFIDENCE && (
+          <LowConfidence data-testid="ocr-low-confidence">
+            low confidence ({Math.round(media.ocr_confidence * 100)}%), please review
+          </LowConfidence>
+        )}
+      </SectionLabel>
       <Description
         $editing={editingOcrText}
         value={editingOcrText ? editOcrText : (media.ocr_text ?? '')}